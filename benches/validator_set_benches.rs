@@ -0,0 +1,56 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use multiversx_bls::{SecretKey, ValidatorSet};
+
+const N: usize = 400;
+
+fn aggregate_public_key(c: &mut Criterion) {
+    let mut secret_key = SecretKey::default();
+    let public_keys = (0..N)
+        .map(|_| {
+            secret_key.set_by_csprng();
+            secret_key.get_public_key()
+        })
+        .collect();
+    let validator_set = ValidatorSet::new(public_keys).unwrap();
+
+    let mut group = c.benchmark_group("aggregate public key of 400 validators");
+    group.sample_size(10);
+    for signers in [
+        N / 10,
+        N / 4,
+        N / 2,
+        3 * N / 4,
+        2 * N / 3 + 1,
+        9 * N / 10,
+        N,
+    ] {
+        let indices: Vec<usize> = (0..signers).collect();
+        let bitmap = validator_set.bitmap(&indices).unwrap();
+
+        group.bench_with_input(BenchmarkId::new("sum", signers), &bitmap, |b, bitmap| {
+            b.iter(|| {
+                black_box(validator_set.aggregate_public_key_by_sum(bitmap).unwrap());
+            })
+        });
+        group.bench_with_input(
+            BenchmarkId::new("complement", signers),
+            &bitmap,
+            |b, bitmap| {
+                b.iter(|| {
+                    black_box(
+                        validator_set
+                            .aggregate_public_key_by_complement(bitmap)
+                            .unwrap(),
+                    );
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, aggregate_public_key);
+criterion_main!(benches);
@@ -0,0 +1,74 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+
+use crate::BlsError;
+
+/// write `contents` to the file at `path`, so that a crash leaves either the previous or the
+/// new contents
+///
+/// The contents are written and synced to a temporary file next to `path`, which is renamed
+/// over it before its directory is synced.
+pub(crate) fn write_file_atomic(path: &Path, contents: &[u8]) -> Result<(), BlsError> {
+    write(path, contents, false)
+}
+
+/// write the secret `contents` to the file at `path` as `write_file_atomic` does, the file
+/// being only readable and writable by its owner on Unix
+pub(crate) fn write_private_file_atomic(path: &Path, contents: &[u8]) -> Result<(), BlsError> {
+    write(path, contents, true)
+}
+
+fn write(path: &Path, contents: &[u8], private: bool) -> Result<(), BlsError> {
+    let mut tmp_path = path.as_os_str().to_owned();
+    tmp_path.push(".tmp");
+
+    let result = create(Path::new(&tmp_path), private)
+        .and_then(|mut file| {
+            file.write_all(contents)?;
+            file.sync_all()
+        })
+        .and_then(|_| fs::rename(&tmp_path, path));
+    if let Err(err) = result {
+        let _ = fs::remove_file(&tmp_path);
+        return Err(BlsError::IoError(err.kind()));
+    }
+
+    // directories cannot be opened for syncing on every platform
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        if let Ok(dir) = File::open(dir) {
+            let _ = dir.sync_all();
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(unix)]
+fn create(path: &Path, private: bool) -> io::Result<File> {
+    use std::fs::Permissions;
+    use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+
+    if !private {
+        return File::create(path);
+    }
+    let file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(path)?;
+    // the mode only applies to new files, not to a temporary file left by a crash
+    file.set_permissions(Permissions::from_mode(0o600))?;
+
+    Ok(file)
+}
+
+#[cfg(not(unix))]
+fn create(path: &Path, _private: bool) -> io::Result<File> {
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(path)
+}
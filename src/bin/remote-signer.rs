@@ -0,0 +1,83 @@
+//! Remote signer serving the keys of a PEM file, as `allValidatorsKeys.pem`.
+//!
+//! Usage: `remote-signer [--allow-remote] <keys.pem> <token-file> [address]`, the address
+//! defaulting to `127.0.0.1:8911`. Non-loopback addresses are refused unless `--allow-remote` is
+//! given. The bearer token of the clients is read from `token-file`, which is created with a new
+//! random token, only readable by its owner, if it does not exist.
+
+use std::io::ErrorKind;
+use std::process::ExitCode;
+
+use multiversx_bls::{BlsError, KeyRing, RemoteSignerServer, RemoteSignerToken};
+
+const DEFAULT_ADDR: &str = "127.0.0.1:8911";
+
+fn main() -> ExitCode {
+    let mut args: Vec<String> = std::env::args().collect();
+    let allow_remote = args.get(1).map(String::as_str) == Some("--allow-remote");
+    if allow_remote {
+        args.remove(1);
+    }
+    if args.len() < 3 || args.len() > 4 {
+        eprintln!(
+            "usage: {} [--allow-remote] <keys.pem> <token-file> [address]",
+            args[0]
+        );
+        return ExitCode::FAILURE;
+    }
+
+    let key_ring = match KeyRing::load(&args[1]) {
+        Ok(key_ring) => key_ring.with_checked_signing(true),
+        Err(err) => {
+            eprintln!("cannot load {}: {err}", args[1]);
+            return ExitCode::FAILURE;
+        }
+    };
+    let token = match RemoteSignerToken::read_file(&args[2]) {
+        Ok(token) => token,
+        Err(BlsError::IoError(ErrorKind::NotFound)) => {
+            match RemoteSignerToken::generate().and_then(|token| {
+                token.write_file(&args[2])?;
+                Ok(token)
+            }) {
+                Ok(token) => {
+                    eprintln!("wrote a new token to {}", args[2]);
+                    token
+                }
+                Err(err) => {
+                    eprintln!("cannot create {}: {err}", args[2]);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        Err(err) => {
+            eprintln!("cannot read the token of {}: {err}", args[2]);
+            return ExitCode::FAILURE;
+        }
+    };
+    let addr = args.get(3).map(String::as_str).unwrap_or(DEFAULT_ADDR);
+    let bound = if allow_remote {
+        RemoteSignerServer::bind_remote(addr, key_ring, token)
+    } else {
+        RemoteSignerServer::bind(addr, key_ring, token)
+    };
+    let server = match bound {
+        Ok(server) => server,
+        Err(BlsError::InvalidAddress) => {
+            eprintln!("{addr} is not a loopback address, pass --allow-remote to listen on it");
+            return ExitCode::FAILURE;
+        }
+        Err(err) => {
+            eprintln!("cannot listen on {addr}: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    eprintln!("serving keys on {addr}");
+    if let Err(err) = server.serve() {
+        eprintln!("{err}");
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
+}
@@ -1,3 +1,4 @@
+use crate::constants::MCLBN_FP_UNIT_SIZE;
 use crate::g1::G1;
 use crate::g2::G2;
 use crate::gt::GT;
@@ -8,6 +9,11 @@ unsafe extern "C" {
 
     pub unsafe fn mclBnFr_isZero(secret_key: *const SecretKey) -> i32;
     pub unsafe fn mclBnFr_isValid(secret_key: *const SecretKey) -> i32;
+    pub unsafe fn mclBnFr_setBigEndianMod(
+        secret_key: *mut SecretKey,
+        buf: *const u8,
+        buf_size: usize,
+    ) -> i32;
     pub unsafe fn mclBn_getFrByteSize() -> usize;
     pub unsafe fn mclBn_getFpByteSize() -> usize;
     pub unsafe fn mclBnG2_setStr(x: *mut G2, buf: *const u8, buf_size: usize, io_mode: i32) -> i32;
@@ -74,6 +80,48 @@ unsafe extern "C" {
 
     pub unsafe fn blsPublicKeyAdd(public_key_1: *mut G2, public_key_2: *const G2);
     pub unsafe fn blsSignatureAdd(signature_1: *mut G1, signature_2: *const G1);
+    pub unsafe fn blsPublicKeySub(public_key_1: *mut G2, public_key_2: *const G2);
+    pub unsafe fn blsPublicKeyMul(public_key: *mut G2, scalar: *const SecretKey);
+    pub unsafe fn blsSignatureMul(signature: *mut G1, scalar: *const SecretKey);
+    pub unsafe fn blsSecretKeyShare(
+        secret_key: *mut SecretKey,
+        msk: *const SecretKey,
+        k: usize,
+        id: *const SecretKey,
+    ) -> i32;
+    pub unsafe fn blsSignatureRecover(
+        signature: *mut G1,
+        signatures: *const G1,
+        ids: *const SecretKey,
+        n: usize,
+    ) -> i32;
+    pub unsafe fn blsVerifyPairing(
+        signature: *const G1,
+        hm: *const G1,
+        public_key: *const G2,
+    ) -> i32;
+
+    pub unsafe fn mclBnG1_hashAndMapToWithDst(
+        x: *mut G1,
+        buf: *const u8,
+        buf_size: usize,
+        dst: *const u8,
+        dst_size: usize,
+    ) -> i32;
+
+    pub unsafe fn mclBnG1_normalize(y: *mut G1, x: *const G1);
+    pub unsafe fn mclBnG2_normalize(y: *mut G2, x: *const G2);
+    pub unsafe fn mclBnG1_neg(y: *mut G1, x: *const G1);
+    pub unsafe fn mclBnG2_neg(y: *mut G2, x: *const G2);
+    pub unsafe fn mclBnFp_serialize(
+        buf: *mut u8,
+        max_buf_size: usize,
+        x: *const [u64; MCLBN_FP_UNIT_SIZE],
+    ) -> usize;
+    pub unsafe fn mclBnFp_neg(
+        y: *mut [u64; MCLBN_FP_UNIT_SIZE],
+        x: *const [u64; MCLBN_FP_UNIT_SIZE],
+    );
 
     pub unsafe fn mclBnGT_isEqual(lhs: *const GT, rhs: *const GT) -> i32;
 }
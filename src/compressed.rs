@@ -0,0 +1,211 @@
+use crate::bls_api::{
+    mclBnFp_neg, mclBnFp_serialize, mclBnG1_neg, mclBnG1_normalize, mclBnG2_neg, mclBnG2_normalize,
+};
+use crate::constants::MCLBN_FP_UNIT_SIZE;
+use crate::g1::G1;
+use crate::g2::G2;
+use crate::init::{init_library, INIT};
+use crate::secret_key::SecretKey;
+use crate::BlsError;
+
+/// size in bytes of the compressed encoding of a `G1` point
+pub const G1_COMPRESSED_SIZE: usize = 48;
+/// size in bytes of the compressed encoding of a `G2` point
+pub const G2_COMPRESSED_SIZE: usize = 96;
+
+type Fp = [u64; MCLBN_FP_UNIT_SIZE];
+
+// compressed encoding of the generator of `G2` of the BLS12-381 specification, which is not the
+// generator of herumi
+const STANDARD_G2_GENERATOR_HEX: &str = "93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8";
+
+const FP_SIZE: usize = 48;
+
+// flags of the first byte of the compressed encodings
+const COMPRESSION_FLAG: u8 = 0x80;
+const INFINITY_FLAG: u8 = 0x40;
+const SIGN_FLAG: u8 = 0x20;
+const FLAGS: u8 = COMPRESSION_FLAG | INFINITY_FLAG | SIGN_FLAG;
+
+impl G1 {
+    /// return the compressed encoding of `self` of the ZCash BLS12-381 serialization, used by
+    /// the IETF drafts, multicodecs and most other BLS libraries
+    ///
+    /// The big-endian `x` coordinate is prefixed by the compression flag `0x80`, the infinity
+    /// flag `0x40` and the flag `0x20` set if `y` is the lexicographically largest root.
+    pub fn serialize_compressed(&self) -> Result<Vec<u8>, BlsError> {
+        if self.is_zero() {
+            return Ok(infinity(G1_COMPRESSED_SIZE));
+        }
+
+        INIT.call_once(init_library);
+        let mut affine = G1::default();
+        unsafe { mclBnG1_normalize(&mut affine, self) };
+
+        let mut buf = fp_to_bytes(&affine.x)?;
+        buf[0] |= COMPRESSION_FLAG;
+        if is_largest(&affine.y)? {
+            buf[0] |= SIGN_FLAG;
+        }
+
+        Ok(buf)
+    }
+
+    /// return the point of the compressed encoding `buf`
+    ///
+    /// Returns `BlsError::InvalidData` if `buf` is not the canonical encoding of a point of the
+    /// prime order subgroup.
+    pub fn from_compressed(buf: &[u8]) -> Result<G1, BlsError> {
+        if buf.len() != G1_COMPRESSED_SIZE {
+            return Err(BlsError::InvalidData);
+        }
+        if is_infinity(buf)? {
+            return Ok(G1::default());
+        }
+
+        let mut point = G1::from_serialized(&native_x(buf))?;
+        if point.is_zero() || !point.is_valid_order() {
+            return Err(BlsError::InvalidData);
+        }
+        if point.serialize_compressed()?[0] & SIGN_FLAG != buf[0] & SIGN_FLAG {
+            let mut negated = G1::default();
+            unsafe { mclBnG1_neg(&mut negated, &point) };
+            point = negated;
+        }
+        if point.serialize_compressed()? != buf {
+            return Err(BlsError::InvalidData);
+        }
+
+        Ok(point)
+    }
+}
+
+impl G2 {
+    /// return the compressed encoding of `self` of the ZCash BLS12-381 serialization, used by
+    /// the IETF drafts, multicodecs and most other BLS libraries
+    ///
+    /// The big-endian `x.c1` and `x.c0` coordinates are prefixed by the flags of
+    /// `G1::serialize_compressed`, the sign flag comparing `y.c1`, then `y.c0` if it is zero.
+    pub fn serialize_compressed(&self) -> Result<Vec<u8>, BlsError> {
+        if self.is_zero() {
+            return Ok(infinity(G2_COMPRESSED_SIZE));
+        }
+
+        INIT.call_once(init_library);
+        let mut affine = G2::default();
+        unsafe { mclBnG2_normalize(&mut affine, self) };
+
+        let mut buf = fp_to_bytes(&affine.x[1])?;
+        buf.extend(fp_to_bytes(&affine.x[0])?);
+        buf[0] |= COMPRESSION_FLAG;
+        let largest = if fp_to_bytes(&affine.y[1])?.iter().all(|b| *b == 0) {
+            is_largest(&affine.y[0])?
+        } else {
+            is_largest(&affine.y[1])?
+        };
+        if largest {
+            buf[0] |= SIGN_FLAG;
+        }
+
+        Ok(buf)
+    }
+
+    /// return the point of the compressed encoding `buf`
+    ///
+    /// Returns `BlsError::InvalidData` if `buf` is not the canonical encoding of a point of the
+    /// prime order subgroup.
+    pub fn from_compressed(buf: &[u8]) -> Result<G2, BlsError> {
+        if buf.len() != G2_COMPRESSED_SIZE {
+            return Err(BlsError::InvalidData);
+        }
+        if is_infinity(buf)? {
+            return Ok(G2::default());
+        }
+
+        // the native encoding holds `x.c0` then `x.c1`
+        let mut native = native_x(&buf[FP_SIZE..]);
+        native.extend(native_x(&buf[..FP_SIZE]));
+        let mut point = G2::from_serialized(&native)?;
+        if point.is_zero() || !point.is_valid_order() {
+            return Err(BlsError::InvalidData);
+        }
+        if point.serialize_compressed()?[0] & SIGN_FLAG != buf[0] & SIGN_FLAG {
+            let mut negated = G2::default();
+            unsafe { mclBnG2_neg(&mut negated, &point) };
+            point = negated;
+        }
+        if point.serialize_compressed()? != buf {
+            return Err(BlsError::InvalidData);
+        }
+
+        Ok(point)
+    }
+}
+
+impl SecretKey {
+    /// return the public key of `self` for the standard generator of `G2`, the one other
+    /// BLS12-381 libraries derive from the same secret key
+    ///
+    /// The public keys of `get_public_key`, used by MultiversX, are multiples of the generator of
+    /// herumi, so other libraries never derive them from the secret key.
+    pub fn get_standard_public_key(&self) -> Result<G2, BlsError> {
+        let generator =
+            hex::decode(STANDARD_G2_GENERATOR_HEX).map_err(|_| BlsError::InternalError)?;
+        let mut public_key = G2::from_compressed(&generator)?;
+        public_key.mul_assign(self);
+
+        Ok(public_key)
+    }
+}
+
+fn infinity(size: usize) -> Vec<u8> {
+    let mut buf = vec![0u8; size];
+    buf[0] = COMPRESSION_FLAG | INFINITY_FLAG;
+    buf
+}
+
+/// return true if `buf` encodes the point at infinity, checking the flags of `buf`
+fn is_infinity(buf: &[u8]) -> Result<bool, BlsError> {
+    if buf[0] & COMPRESSION_FLAG == 0 {
+        return Err(BlsError::InvalidData);
+    }
+    if buf[0] & INFINITY_FLAG == 0 {
+        return Ok(false);
+    }
+    if buf[0] != COMPRESSION_FLAG | INFINITY_FLAG || buf[1..].iter().any(|b| *b != 0) {
+        return Err(BlsError::InvalidData);
+    }
+
+    Ok(true)
+}
+
+/// return the little-endian `x` coordinate of the big-endian `buf`, without the flags
+///
+/// The native encoding of herumi stores the parity of `y` in the most significant bit, left
+/// clear so that either root is selected.
+fn native_x(buf: &[u8]) -> Vec<u8> {
+    let mut x = buf.to_vec();
+    x[0] &= !FLAGS;
+    x.reverse();
+    x
+}
+
+/// return the big-endian canonical encoding of `x`
+fn fp_to_bytes(x: &Fp) -> Result<Vec<u8>, BlsError> {
+    let mut buf = vec![0u8; FP_SIZE];
+    let n = unsafe { mclBnFp_serialize(buf.as_mut_ptr(), FP_SIZE, x) };
+    if n != FP_SIZE {
+        return Err(BlsError::SerializeError);
+    }
+    buf.reverse();
+
+    Ok(buf)
+}
+
+/// return true if `y` is greater than `-y`
+fn is_largest(y: &Fp) -> Result<bool, BlsError> {
+    let mut negated = Fp::default();
+    unsafe { mclBnFp_neg(&mut negated, y) };
+
+    Ok(fp_to_bytes(y)? > fp_to_bytes(&negated)?)
+}
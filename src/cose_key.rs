@@ -0,0 +1,233 @@
+use sha2::{Digest, Sha256};
+
+use crate::g2::G2;
+use crate::key_derivation::{secret_key_from_be_bytes, secret_key_to_be_bytes};
+use crate::secret_key::SecretKey;
+use crate::BlsError;
+
+/// COSE key type of BLS12-381 keys (OKP)
+pub const COSE_KEY_TYPE_OKP: i64 = 1;
+/// COSE curve of BLS12-381 keys whose public key is in `G2`
+pub const COSE_CURVE_BLS12381_G2: i64 = 14;
+
+const LABEL_KTY: i64 = 1;
+const LABEL_CRV: i64 = -1;
+const LABEL_X: i64 = -2;
+const LABEL_D: i64 = -4;
+
+const MAJOR_UNSIGNED: u8 = 0;
+const MAJOR_NEGATIVE: u8 = 1;
+const MAJOR_BYTES: u8 = 2;
+const MAJOR_TEXT: u8 = 3;
+const MAJOR_MAP: u8 = 5;
+
+impl G2 {
+    /// return the COSE_Key of `self`, in deterministic CBOR, with the compressed point of
+    /// `serialize_compressed` as `x`
+    pub fn to_cose_key(&self) -> Result<Vec<u8>, BlsError> {
+        Ok(encode_cose_key(&self.serialize_compressed()?, None))
+    }
+
+    /// return the public key of the COSE_Key `buf`
+    ///
+    /// If `buf` also holds a secret key, the public key must be its standard public key, of
+    /// `SecretKey::get_standard_public_key`, which is returned.
+    pub fn from_cose_key(buf: &[u8]) -> Result<G2, BlsError> {
+        Ok(decode_cose_key(buf)?.0)
+    }
+
+    /// return the RFC 9679 SHA-256 thumbprint of the COSE_Key of `self`
+    pub fn cose_key_thumbprint(&self) -> Result<Vec<u8>, BlsError> {
+        Ok(Sha256::digest(self.to_cose_key()?).to_vec())
+    }
+}
+
+impl SecretKey {
+    /// return the COSE_Key of `self`, in deterministic CBOR
+    ///
+    /// `x` is the standard public key of `get_standard_public_key`, the one other libraries derive
+    /// from `d`, not the MultiversX public key of `get_public_key`.
+    pub fn to_cose_key(&self) -> Result<Vec<u8>, BlsError> {
+        Ok(encode_cose_key(
+            &self.get_standard_public_key()?.serialize_compressed()?,
+            Some(&secret_key_to_be_bytes(self)?),
+        ))
+    }
+
+    /// return the secret key of the COSE_Key `buf`
+    pub fn from_cose_key(buf: &[u8]) -> Result<SecretKey, BlsError> {
+        decode_cose_key(buf)?.1.ok_or(BlsError::InvalidCoseKey)
+    }
+}
+
+fn encode_cose_key(x: &[u8], d: Option<&[u8]>) -> Vec<u8> {
+    let mut buf = Vec::new();
+    encode_head(&mut buf, MAJOR_MAP, if d.is_some() { 4 } else { 3 });
+    encode_int(&mut buf, LABEL_KTY);
+    encode_int(&mut buf, COSE_KEY_TYPE_OKP);
+    encode_int(&mut buf, LABEL_CRV);
+    encode_int(&mut buf, COSE_CURVE_BLS12381_G2);
+    encode_int(&mut buf, LABEL_X);
+    encode_bytes(&mut buf, x);
+    if let Some(d) = d {
+        encode_int(&mut buf, LABEL_D);
+        encode_bytes(&mut buf, d);
+    }
+
+    buf
+}
+
+fn decode_cose_key(buf: &[u8]) -> Result<(G2, Option<SecretKey>), BlsError> {
+    let mut reader = CborReader { buf };
+    let (major, len) = reader.head()?;
+    if major != MAJOR_MAP {
+        return Err(BlsError::InvalidCoseKey);
+    }
+
+    let (mut kty, mut crv, mut x, mut d) = (None, None, None, None);
+    let mut labels = Vec::new();
+    for _ in 0..len {
+        let label = match reader.value()? {
+            CborValue::Int(label) => label,
+            CborValue::Text => {
+                reader.value()?;
+                continue;
+            }
+            CborValue::Bytes(_) => return Err(BlsError::InvalidCoseKey),
+        };
+        if labels.contains(&label) {
+            return Err(BlsError::InvalidCoseKey);
+        }
+        labels.push(label);
+
+        let value = reader.value()?;
+        match (label, value) {
+            (LABEL_KTY, CborValue::Int(v)) => kty = Some(v),
+            (LABEL_CRV, CborValue::Int(v)) => crv = Some(v),
+            (LABEL_X, CborValue::Bytes(v)) => x = Some(v),
+            (LABEL_D, CborValue::Bytes(v)) => d = Some(v),
+            (LABEL_KTY | LABEL_CRV | LABEL_X | LABEL_D, _) => return Err(BlsError::InvalidCoseKey),
+            _ => {}
+        }
+    }
+    if !reader.buf.is_empty()
+        || kty != Some(COSE_KEY_TYPE_OKP)
+        || crv != Some(COSE_CURVE_BLS12381_G2)
+    {
+        return Err(BlsError::InvalidCoseKey);
+    }
+
+    let public_key = G2::from_compressed(x.ok_or(BlsError::InvalidCoseKey)?)
+        .map_err(|_| BlsError::InvalidCoseKey)?;
+    if public_key.is_zero() || !public_key.is_valid_order() {
+        return Err(BlsError::InvalidCoseKey);
+    }
+
+    let secret_key = match d {
+        Some(d) => {
+            let secret_key = secret_key_from_be_bytes(d).map_err(|_| BlsError::InvalidCoseKey)?;
+            if secret_key.get_standard_public_key()? != public_key {
+                return Err(BlsError::PublicKeyMismatch);
+            }
+            Some(secret_key)
+        }
+        None => None,
+    };
+
+    Ok((public_key, secret_key))
+}
+
+fn encode_head(buf: &mut Vec<u8>, major: u8, arg: u64) {
+    let major = major << 5;
+    match arg {
+        0..=23 => buf.push(major | arg as u8),
+        24..=0xff => buf.extend([major | 24, arg as u8]),
+        0x100..=0xffff => {
+            buf.push(major | 25);
+            buf.extend((arg as u16).to_be_bytes());
+        }
+        0x10000..=0xffff_ffff => {
+            buf.push(major | 26);
+            buf.extend((arg as u32).to_be_bytes());
+        }
+        _ => {
+            buf.push(major | 27);
+            buf.extend(arg.to_be_bytes());
+        }
+    }
+}
+
+fn encode_int(buf: &mut Vec<u8>, v: i64) {
+    if v >= 0 {
+        encode_head(buf, MAJOR_UNSIGNED, v as u64);
+    } else {
+        encode_head(buf, MAJOR_NEGATIVE, !v as u64);
+    }
+}
+
+fn encode_bytes(buf: &mut Vec<u8>, bytes: &[u8]) {
+    encode_head(buf, MAJOR_BYTES, bytes.len() as u64);
+    buf.extend_from_slice(bytes);
+}
+
+enum CborValue<'a> {
+    Int(i64),
+    Bytes(&'a [u8]),
+    Text,
+}
+
+/// reader of the CBOR values which may appear in a COSE_Key: integers, byte and text
+/// strings, all of definite length
+struct CborReader<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> CborReader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], BlsError> {
+        if self.buf.len() < n {
+            return Err(BlsError::InvalidCoseKey);
+        }
+        let (head, rest) = self.buf.split_at(n);
+        self.buf = rest;
+
+        Ok(head)
+    }
+
+    fn head(&mut self) -> Result<(u8, u64), BlsError> {
+        let initial = self.take(1)?[0];
+        let arg = match initial & 0x1f {
+            n @ 0..=23 => n as u64,
+            n @ 24..=27 => {
+                let size = 1 << (n - 24);
+                self.take(size)?
+                    .iter()
+                    .fold(0u64, |arg, b| (arg << 8) | *b as u64)
+            }
+            _ => return Err(BlsError::InvalidCoseKey),
+        };
+
+        Ok((initial >> 5, arg))
+    }
+
+    fn value(&mut self) -> Result<CborValue<'a>, BlsError> {
+        let (major, arg) = self.head()?;
+        match major {
+            MAJOR_UNSIGNED => i64::try_from(arg)
+                .map(CborValue::Int)
+                .map_err(|_| BlsError::InvalidCoseKey),
+            MAJOR_NEGATIVE => i64::try_from(arg)
+                .map(|arg| CborValue::Int(!arg))
+                .map_err(|_| BlsError::InvalidCoseKey),
+            MAJOR_BYTES => Ok(CborValue::Bytes(self.take_len(arg)?)),
+            MAJOR_TEXT => {
+                std::str::from_utf8(self.take_len(arg)?).map_err(|_| BlsError::InvalidCoseKey)?;
+                Ok(CborValue::Text)
+            }
+            _ => Err(BlsError::InvalidCoseKey),
+        }
+    }
+
+    fn take_len(&mut self, len: u64) -> Result<&'a [u8], BlsError> {
+        self.take(usize::try_from(len).map_err(|_| BlsError::InvalidCoseKey)?)
+    }
+}
@@ -0,0 +1,185 @@
+use serde::{Deserialize, Serialize};
+
+use crate::g1::G1;
+use crate::g2::G2;
+use crate::BlsError;
+
+/// multicodec code of BLS12-381 public keys in `G1` (`bls12_381-g1-pub`)
+pub const MULTICODEC_BLS12_381_G1_PUB: u64 = 0xea;
+/// multicodec code of BLS12-381 public keys in `G2` (`bls12_381-g2-pub`)
+pub const MULTICODEC_BLS12_381_G2_PUB: u64 = 0xeb;
+
+/// prefix of `did:key` identifiers
+pub const DID_KEY_PREFIX: &str = "did:key:";
+
+const MULTIBASE_BASE58_BTC: char = 'z';
+
+const DID_CONTEXT: &str = "https://www.w3.org/ns/did/v1";
+const BLS12381_2020_CONTEXT: &str = "https://w3id.org/security/suites/bls12381-2020/v1";
+const G1_KEY_TYPE: &str = "Bls12381G1Key2020";
+const G2_KEY_TYPE: &str = "Bls12381G2Key2020";
+
+/// verification method of a `DidDocument`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationMethod {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub key_type: String,
+    pub controller: String,
+    pub public_key_base58: String,
+}
+
+/// DID document of a `did:key` identifier
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidDocument {
+    #[serde(rename = "@context")]
+    pub context: Vec<String>,
+    pub id: String,
+    pub verification_method: Vec<VerificationMethod>,
+    pub authentication: Vec<String>,
+    pub assertion_method: Vec<String>,
+    pub capability_delegation: Vec<String>,
+    pub capability_invocation: Vec<String>,
+}
+
+impl DidDocument {
+    /// return `self` as a JSON object
+    pub fn to_json(&self) -> Result<String, BlsError> {
+        serde_json::to_string_pretty(self).map_err(|_| BlsError::SerializeError)
+    }
+}
+
+impl G1 {
+    /// return the base58btc multibase of the `bls12_381-g1-pub` multicodec of the compressed `self`
+    pub fn to_multibase(&self) -> Result<String, BlsError> {
+        Ok(encode_multibase(
+            MULTICODEC_BLS12_381_G1_PUB,
+            &self.serialize_compressed()?,
+        ))
+    }
+
+    /// return the `G1` public key of the multibase `s`
+    pub fn from_multibase(s: &str) -> Result<G1, BlsError> {
+        let buf = decode_multibase(MULTICODEC_BLS12_381_G1_PUB, s)?;
+        let key = G1::from_compressed(&buf).map_err(|_| BlsError::InvalidMultibase)?;
+        if key.is_zero() || !key.is_valid_order() {
+            return Err(BlsError::InvalidMultibase);
+        }
+
+        Ok(key)
+    }
+
+    /// return the `did:key` identifier of `self`
+    pub fn to_did_key(&self) -> Result<String, BlsError> {
+        Ok(format!("{DID_KEY_PREFIX}{}", self.to_multibase()?))
+    }
+
+    /// return the `G1` public key of the `did:key` identifier `did`
+    pub fn from_did_key(did: &str) -> Result<G1, BlsError> {
+        G1::from_multibase(did_key_fingerprint(did)?)
+    }
+}
+
+impl G2 {
+    /// return the base58btc multibase of the `bls12_381-g2-pub` multicodec of the compressed `self`
+    pub fn to_multibase(&self) -> Result<String, BlsError> {
+        Ok(encode_multibase(
+            MULTICODEC_BLS12_381_G2_PUB,
+            &self.serialize_compressed()?,
+        ))
+    }
+
+    /// return the `G2` public key of the multibase `s`
+    pub fn from_multibase(s: &str) -> Result<G2, BlsError> {
+        let buf = decode_multibase(MULTICODEC_BLS12_381_G2_PUB, s)?;
+        let key = G2::from_compressed(&buf).map_err(|_| BlsError::InvalidMultibase)?;
+        if key.is_zero() || !key.is_valid_order() {
+            return Err(BlsError::InvalidMultibase);
+        }
+
+        Ok(key)
+    }
+
+    /// return the `did:key` identifier of `self`
+    pub fn to_did_key(&self) -> Result<String, BlsError> {
+        Ok(format!("{DID_KEY_PREFIX}{}", self.to_multibase()?))
+    }
+
+    /// return the `G2` public key of the `did:key` identifier `did`
+    pub fn from_did_key(did: &str) -> Result<G2, BlsError> {
+        G2::from_multibase(did_key_fingerprint(did)?)
+    }
+}
+
+/// return the DID document of the `did:key` identifier `did` of a `G1` or `G2` public key
+pub fn resolve_did_key(did: &str) -> Result<DidDocument, BlsError> {
+    let fingerprint = did_key_fingerprint(did)?;
+    let (key_type, key) = match G2::from_multibase(fingerprint) {
+        Ok(key) => (G2_KEY_TYPE, key.serialize_compressed()?),
+        Err(_) => (
+            G1_KEY_TYPE,
+            G1::from_multibase(fingerprint)?.serialize_compressed()?,
+        ),
+    };
+
+    let method_id = format!("{did}#{fingerprint}");
+    Ok(DidDocument {
+        context: vec![DID_CONTEXT.to_string(), BLS12381_2020_CONTEXT.to_string()],
+        id: did.to_string(),
+        verification_method: vec![VerificationMethod {
+            id: method_id.clone(),
+            key_type: key_type.to_string(),
+            controller: did.to_string(),
+            public_key_base58: bs58::encode(key).into_string(),
+        }],
+        authentication: vec![method_id.clone()],
+        assertion_method: vec![method_id.clone()],
+        capability_delegation: vec![method_id.clone()],
+        capability_invocation: vec![method_id],
+    })
+}
+
+fn did_key_fingerprint(did: &str) -> Result<&str, BlsError> {
+    did.strip_prefix(DID_KEY_PREFIX)
+        .filter(|fingerprint| !fingerprint.contains([':', '#', '/', '?']))
+        .ok_or(BlsError::InvalidDid)
+}
+
+fn encode_multibase(code: u64, key: &[u8]) -> String {
+    let mut buf = Vec::new();
+    let mut code = code;
+    while code >= 0x80 {
+        buf.push((code as u8 & 0x7f) | 0x80);
+        code >>= 7;
+    }
+    buf.push(code as u8);
+    buf.extend_from_slice(key);
+
+    format!("{MULTIBASE_BASE58_BTC}{}", bs58::encode(buf).into_string())
+}
+
+/// return the key of the multibase `s`, whose multicodec must be `code`
+fn decode_multibase(code: u64, s: &str) -> Result<Vec<u8>, BlsError> {
+    let encoded = s
+        .strip_prefix(MULTIBASE_BASE58_BTC)
+        .ok_or(BlsError::InvalidMultibase)?;
+    let buf = bs58::decode(encoded)
+        .into_vec()
+        .map_err(|_| BlsError::InvalidMultibase)?;
+
+    let mut actual_code = 0u64;
+    for (i, b) in buf.iter().enumerate().take(9) {
+        actual_code |= ((b & 0x7f) as u64) << (7 * i);
+        if b & 0x80 == 0 {
+            // varints must be minimal
+            if actual_code != code || (i > 0 && *b == 0) {
+                return Err(BlsError::InvalidMultibase);
+            }
+            return Ok(buf[i + 1..].to_vec());
+        }
+    }
+
+    Err(BlsError::InvalidMultibase)
+}
@@ -0,0 +1,198 @@
+use serde::{Deserialize, Serialize};
+
+use crate::g1::G1;
+use crate::g2::G2;
+use crate::BlsError;
+
+const PUBLIC_KEY_SIZE: usize = 96;
+const SIGNATURE_SIZE: usize = 48;
+
+/// consensus slot a validator signs at most one payload for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Slot {
+    pub epoch: u32,
+    pub round: u64,
+    /// type of the signed consensus message
+    pub kind: u8,
+}
+
+/// evidence of two valid signatures of conflicting payloads by the same key for the same slot
+///
+/// The signatures are of the payloads themselves, as produced by `SecretKey::sign` and
+/// `SigningProtection::sign`. The payloads must hold their slot, as headers do: the evidence is
+/// checked with a `slot_of` function returning the slot of a payload, e.g. by decoding the
+/// header it is, so that signatures of other slots are never evidence for `slot`.
+///
+/// The canonical serialization is the public key, the big-endian epoch and round, the kind,
+/// then for each payload in increasing order its big-endian 4-byte length, the payload and its
+/// signature.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Equivocation {
+    public_key: G2,
+    slot: Slot,
+    first: (Vec<u8>, G1),
+    second: (Vec<u8>, G1),
+}
+
+impl Equivocation {
+    /// return the evidence of the signatures `first_signature` of `first_payload` and
+    /// `second_signature` of `second_payload` by `public_key` for `slot`
+    /// * `slot_of` - function returning the slot of a payload
+    ///
+    /// Returns the errors of `verify`.
+    pub fn new<F: Fn(&[u8]) -> Result<Slot, BlsError>>(
+        public_key: G2,
+        slot: Slot,
+        (first_payload, first_signature): (&[u8], G1),
+        (second_payload, second_signature): (&[u8], G1),
+        slot_of: F,
+    ) -> Result<Self, BlsError> {
+        let mut first = (first_payload.to_vec(), first_signature);
+        let mut second = (second_payload.to_vec(), second_signature);
+        if second.0 < first.0 {
+            std::mem::swap(&mut first, &mut second);
+        }
+
+        let equivocation = Equivocation {
+            public_key,
+            slot,
+            first,
+            second,
+        };
+        equivocation.verify(slot_of)?;
+
+        Ok(equivocation)
+    }
+
+    /// check that `self` holds two valid signatures of distinct payloads of its slot
+    /// * `slot_of` - function returning the slot of a payload
+    ///
+    /// Returns `BlsError::InvalidEvidence` if the payloads are the same or `slot_of` does not
+    /// return the slot of `self` for both, `BlsError::InvalidData` if a key or signature is not
+    /// a valid point and `BlsError::InvalidSignature` if a signature does not verify.
+    pub fn verify<F: Fn(&[u8]) -> Result<Slot, BlsError>>(
+        &self,
+        slot_of: F,
+    ) -> Result<(), BlsError> {
+        if self.first.0 == self.second.0 {
+            return Err(BlsError::InvalidEvidence);
+        }
+        if self.public_key.is_zero() || !self.public_key.is_valid_order() {
+            return Err(BlsError::InvalidData);
+        }
+
+        for (payload, signature) in [&self.first, &self.second] {
+            if slot_of(payload).map_err(|_| BlsError::InvalidEvidence)? != self.slot {
+                return Err(BlsError::InvalidEvidence);
+            }
+            if signature.is_zero() || !signature.is_valid_order() {
+                return Err(BlsError::InvalidData);
+            }
+            if !signature.verify(self.public_key, payload) {
+                return Err(BlsError::InvalidSignature);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// return the public key of the equivocating validator
+    pub fn public_key(&self) -> &G2 {
+        &self.public_key
+    }
+
+    /// return the slot of the conflicting signatures
+    pub fn slot(&self) -> Slot {
+        self.slot
+    }
+
+    /// return the conflicting payloads, in increasing order
+    pub fn payloads(&self) -> (&[u8], &[u8]) {
+        (&self.first.0, &self.second.0)
+    }
+
+    /// return the signatures of `payloads()`
+    pub fn signatures(&self) -> (&G1, &G1) {
+        (&self.first.1, &self.second.1)
+    }
+
+    /// return the canonical serialization of `self`
+    pub fn serialize(&self) -> Result<Vec<u8>, BlsError> {
+        let mut buf = self.public_key.serialize()?;
+        buf.extend_from_slice(&self.slot.epoch.to_be_bytes());
+        buf.extend_from_slice(&self.slot.round.to_be_bytes());
+        buf.push(self.slot.kind);
+
+        for (payload, signature) in [&self.first, &self.second] {
+            let len = u32::try_from(payload.len()).map_err(|_| BlsError::SerializeError)?;
+            buf.extend_from_slice(&len.to_be_bytes());
+            buf.extend_from_slice(payload);
+            buf.extend_from_slice(&signature.serialize()?);
+        }
+
+        Ok(buf)
+    }
+
+    /// return the evidence of the canonical serialization `buf`, once verified with `slot_of`
+    pub fn from_serialized<F: Fn(&[u8]) -> Result<Slot, BlsError>>(
+        buf: &[u8],
+        slot_of: F,
+    ) -> Result<Self, BlsError> {
+        let mut reader = Reader(buf);
+        let public_key = G2::from_serialized(reader.take(PUBLIC_KEY_SIZE)?)?;
+        let slot = Slot {
+            epoch: u32::from_be_bytes(reader.take_array()?),
+            round: u64::from_be_bytes(reader.take_array()?),
+            kind: reader.take_array::<1>()?[0],
+        };
+
+        let mut entries = Vec::with_capacity(2);
+        for _ in 0..2 {
+            let len = u32::from_be_bytes(reader.take_array()?) as usize;
+            let payload = reader.take(len)?.to_vec();
+            let signature = G1::from_serialized(reader.take(SIGNATURE_SIZE)?)?;
+            entries.push((payload, signature));
+        }
+        if !reader.0.is_empty() {
+            return Err(BlsError::InvalidData);
+        }
+
+        let second = entries.pop().ok_or(BlsError::InvalidData)?;
+        let first = entries.pop().ok_or(BlsError::InvalidData)?;
+        // the serialization is canonical only with the payloads in increasing order
+        if first.0 >= second.0 {
+            return Err(BlsError::InvalidEvidence);
+        }
+
+        let equivocation = Equivocation {
+            public_key,
+            slot,
+            first,
+            second,
+        };
+        equivocation.verify(slot_of)?;
+
+        Ok(equivocation)
+    }
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], BlsError> {
+        if self.0.len() < len {
+            return Err(BlsError::BadSize);
+        }
+        let (taken, rest) = self.0.split_at(len);
+        self.0 = rest;
+
+        Ok(taken)
+    }
+
+    fn take_array<const N: usize>(&mut self) -> Result<[u8; N], BlsError> {
+        let mut array = [0u8; N];
+        array.copy_from_slice(self.take(N)?);
+
+        Ok(array)
+    }
+}
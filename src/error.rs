@@ -1,4 +1,4 @@
-use std::fmt;
+use std::{fmt, io};
 
 #[derive(Debug, PartialEq, Clone)]
 /// `BlsError` type for error
@@ -7,6 +7,32 @@ pub enum BlsError {
     BadSize,
     InternalError,
     SerializeError,
+    InvalidDerivationPath,
+    InvalidMnemonic,
+    InvalidPem,
+    PublicKeyMismatch,
+    IoError(io::ErrorKind),
+    UnknownPublicKey,
+    DuplicatePublicKey,
+    InvalidKeystore,
+    InvalidPassword,
+    InvalidEncoding,
+    InvalidJwk,
+    InvalidCoseKey,
+    InvalidMultibase,
+    InvalidDid,
+    InvalidSignature,
+    InvalidBitmap,
+    UnknownSigner,
+    DuplicateSigner,
+    ThresholdNotReached,
+    EpochMismatch,
+    LeaderNotSigned,
+    InvalidAddress,
+    InvalidEvidence,
+    DoubleSigning,
+    SignatureCheckFailed,
+    Unauthorized,
 }
 
 impl fmt::Display for BlsError {
@@ -16,6 +42,34 @@ impl fmt::Display for BlsError {
             BlsError::BadSize => write!(f, "bad parameter size"),
             BlsError::InternalError => write!(f, "internal error"),
             BlsError::SerializeError => write!(f, "serialize error"),
+            BlsError::InvalidDerivationPath => write!(f, "invalid derivation path"),
+            BlsError::InvalidMnemonic => write!(f, "invalid mnemonic"),
+            BlsError::InvalidPem => write!(f, "invalid pem block"),
+            BlsError::PublicKeyMismatch => {
+                write!(f, "public key does not match the secret key")
+            }
+            BlsError::IoError(kind) => write!(f, "io error: {kind}"),
+            BlsError::UnknownPublicKey => write!(f, "unknown public key"),
+            BlsError::DuplicatePublicKey => write!(f, "duplicate public key"),
+            BlsError::InvalidKeystore => write!(f, "invalid keystore"),
+            BlsError::InvalidPassword => write!(f, "invalid password"),
+            BlsError::InvalidEncoding => write!(f, "invalid asn.1 encoding"),
+            BlsError::InvalidJwk => write!(f, "invalid jwk"),
+            BlsError::InvalidCoseKey => write!(f, "invalid cose key"),
+            BlsError::InvalidMultibase => write!(f, "invalid multibase key"),
+            BlsError::InvalidDid => write!(f, "invalid did:key"),
+            BlsError::InvalidSignature => write!(f, "invalid signature"),
+            BlsError::InvalidBitmap => write!(f, "invalid bitmap"),
+            BlsError::UnknownSigner => write!(f, "unknown signer"),
+            BlsError::DuplicateSigner => write!(f, "duplicate signer"),
+            BlsError::ThresholdNotReached => write!(f, "signature threshold not reached"),
+            BlsError::EpochMismatch => write!(f, "epoch mismatch"),
+            BlsError::LeaderNotSigned => write!(f, "leader signature missing"),
+            BlsError::InvalidAddress => write!(f, "invalid address"),
+            BlsError::InvalidEvidence => write!(f, "invalid equivocation evidence"),
+            BlsError::DoubleSigning => write!(f, "conflicting signature refused"),
+            BlsError::SignatureCheckFailed => write!(f, "produced signature does not verify"),
+            BlsError::Unauthorized => write!(f, "unauthorized"),
         }
     }
 }
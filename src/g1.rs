@@ -1,6 +1,7 @@
 use crate::constants::MCLBN_FP_UNIT_SIZE;
 use crate::g2::G2;
 use crate::init::{init_library, INIT};
+use crate::secret_key::SecretKey;
 use crate::{bls_api::*, BlsError};
 
 /// signature type
@@ -55,6 +56,14 @@ impl G1 {
         }
     }
 
+    /// multiply `self` by `scalar`
+    pub fn mul_assign(&mut self, scalar: &SecretKey) {
+        INIT.call_once(init_library);
+        unsafe {
+            blsSignatureMul(self, scalar);
+        }
+    }
+
     /// return true if `self` has the valid order
     pub fn is_valid_order(&self) -> bool {
         INIT.call_once(init_library);
@@ -1,5 +1,6 @@
 use crate::constants::MCLBN_FP_UNIT_SIZE;
 use crate::init::{init_library, INIT};
+use crate::secret_key::SecretKey;
 use crate::{bls_api::*, BlsError};
 
 /// public key type
@@ -34,6 +35,28 @@ impl G2 {
         }
     }
 
+    /// Subtracts the given `public_key` from `self`.
+    ///
+    /// # Arguments
+    /// * `public_key` - A `G2` element to be subtracted from `self`.
+    pub fn sub_assign(&mut self, public_key: G2) {
+        INIT.call_once(init_library);
+        unsafe {
+            blsPublicKeySub(self, &public_key);
+        }
+    }
+
+    /// Multiplies `self` by the given `scalar`.
+    ///
+    /// # Arguments
+    /// * `scalar` - A scalar, represented as a `SecretKey`, by which `self` is multiplied.
+    pub fn mul_assign(&mut self, scalar: &SecretKey) {
+        INIT.call_once(init_library);
+        unsafe {
+            blsPublicKeyMul(self, scalar);
+        }
+    }
+
     /// Checks if the `G2` element has a valid order.
     ///
     /// This function verifies whether the `G2` element represented by `self`
@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+
+use crate::g1::G1;
+use crate::validator_set::ValidatorSet;
+use crate::BlsError;
+
+/// size in bytes of MultiversX header hashes
+pub const HEADER_HASH_SIZE: usize = 32;
+
+/// index of the leader in a MultiversX consensus group
+const LEADER_INDEX: usize = 0;
+
+/// proof of a MultiversX block header, as returned by the node API
+///
+/// The consensus group of the header epoch signs the header hash, and `pub_keys_bitmap` selects
+/// the signers of `aggregated_signature`. Byte fields are hex encoded.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeaderProof {
+    pub pub_keys_bitmap: String,
+    pub aggregated_signature: String,
+    pub header_hash: String,
+    pub header_epoch: u32,
+    pub header_nonce: u64,
+    pub header_shard_id: u32,
+    pub header_round: u64,
+    pub is_start_of_epoch: bool,
+}
+
+impl HeaderProof {
+    /// return the header proof of the JSON object `json`
+    pub fn from_json(json: &str) -> Result<HeaderProof, BlsError> {
+        serde_json::from_str(json).map_err(|_| BlsError::InvalidData)
+    }
+
+    /// return `self` as a JSON object
+    pub fn to_json(&self) -> Result<String, BlsError> {
+        serde_json::to_string_pretty(self).map_err(|_| BlsError::SerializeError)
+    }
+
+    /// return the hash of the proven header
+    pub fn header_hash(&self) -> Result<Vec<u8>, BlsError> {
+        let header_hash = decode_hex(&self.header_hash)?;
+        if header_hash.len() != HEADER_HASH_SIZE {
+            return Err(BlsError::BadSize);
+        }
+
+        Ok(header_hash)
+    }
+
+    /// return the signer bitmap of `self`
+    pub fn bitmap(&self) -> Result<Vec<u8>, BlsError> {
+        decode_hex(&self.pub_keys_bitmap)
+    }
+
+    /// return the aggregated signature of `self`
+    pub fn signature(&self) -> Result<G1, BlsError> {
+        let signature = G1::from_serialized(&decode_hex(&self.aggregated_signature)?)?;
+        if signature.is_zero() || !signature.is_valid_order() {
+            return Err(BlsError::InvalidData);
+        }
+
+        Ok(signature)
+    }
+
+    /// check `self` against `consensus_group`, the consensus group of epoch `epoch` in
+    /// consensus order, its leader first
+    ///
+    /// Returns `BlsError::EpochMismatch` if the proof is not of epoch `epoch`,
+    /// `BlsError::InvalidBitmap` if the bitmap does not fit the consensus group,
+    /// `BlsError::ThresholdNotReached` if less than `2/3 + 1` of the group signed,
+    /// `BlsError::LeaderNotSigned` if the leader did not sign and
+    /// `BlsError::InvalidSignature` if the aggregated signature does not verify.
+    pub fn verify(&self, epoch: u32, consensus_group: &ValidatorSet) -> Result<(), BlsError> {
+        if self.header_epoch != epoch {
+            return Err(BlsError::EpochMismatch);
+        }
+        let header_hash = self.header_hash()?;
+        let bitmap = self.bitmap()?;
+
+        let signers = consensus_group.selected_indices(&bitmap)?;
+        if signers.len() < consensus_group.len() * 2 / 3 + 1 {
+            return Err(BlsError::ThresholdNotReached);
+        }
+        if !signers.contains(&LEADER_INDEX) {
+            return Err(BlsError::LeaderNotSigned);
+        }
+
+        consensus_group.verify_aggregated(&bitmap, &self.signature()?, &header_hash)
+    }
+}
+
+fn decode_hex(s: &str) -> Result<Vec<u8>, BlsError> {
+    hex::decode(s).map_err(|_| BlsError::InvalidData)
+}
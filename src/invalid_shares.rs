@@ -0,0 +1,78 @@
+use crate::g1::G1;
+use crate::g2::G2;
+use crate::secret_key::SecretKey;
+use crate::BlsError;
+
+const BATCH_WEIGHT_SIZE: usize = 16;
+
+/// return the indices of the shares of `msg` which do not verify, in increasing order
+/// * `public_keys` - public keys of the signers, `shares[i]` being the share of `public_keys[i]`
+///
+/// Each share and public key is first weighted by a random 128-bit scalar, so that invalid
+/// shares cannot cancel each other out. The shares are then bisected with aggregated checks,
+/// which takes about `2 * k * log2(n)` pairing checks for `k` invalid shares out of `n`.
+pub fn find_invalid_shares(
+    public_keys: &[G2],
+    shares: &[G1],
+    msg: &[u8],
+) -> Result<Vec<usize>, BlsError> {
+    if public_keys.len() != shares.len() {
+        return Err(BlsError::BadSize);
+    }
+
+    let mut invalid = Vec::new();
+    let mut candidates = Vec::new();
+    for (index, (public_key, share)) in public_keys.iter().zip(shares).enumerate() {
+        if share.is_zero() || !share.is_valid_order() {
+            invalid.push(index);
+            continue;
+        }
+
+        let weight = random_weight()?;
+        let mut public_key = *public_key;
+        let mut share = *share;
+        public_key.mul_assign(&weight);
+        share.mul_assign(&weight);
+        candidates.push((index, public_key, share));
+    }
+
+    let mut ranges = vec![candidates.as_slice()];
+    while let Some(range) = ranges.pop() {
+        if range.is_empty() || verify_range(range, msg) {
+            continue;
+        }
+        if let [(index, _, _)] = range {
+            invalid.push(*index);
+            continue;
+        }
+
+        let (left, right) = range.split_at(range.len() / 2);
+        ranges.push(right);
+        ranges.push(left);
+    }
+    invalid.sort_unstable();
+
+    Ok(invalid)
+}
+
+fn verify_range(range: &[(usize, G2, G1)], msg: &[u8]) -> bool {
+    let mut public_key = G2::default();
+    let mut signature = G1::default();
+    for (_, pk, share) in range {
+        public_key.add_assign(*pk);
+        signature.add_assign(*share);
+    }
+
+    signature.verify(public_key, msg)
+}
+
+fn random_weight() -> Result<SecretKey, BlsError> {
+    let mut buf = [0u8; 32];
+    loop {
+        getrandom::fill(&mut buf[..BATCH_WEIGHT_SIZE]).map_err(|_| BlsError::InternalError)?;
+        let weight = SecretKey::from_serialized(&buf)?;
+        if !weight.is_zero() {
+            return Ok(weight);
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::g2::G2;
+use crate::key_derivation::{secret_key_from_be_bytes, secret_key_to_be_bytes};
+use crate::secret_key::SecretKey;
+use crate::BlsError;
+
+/// JWK key type of BLS12-381 keys
+pub const JWK_KEY_TYPE: &str = "OKP";
+/// JWK curve of BLS12-381 keys whose public key is in `G2`
+pub const JWK_CURVE_BLS12381_G2: &str = "Bls12381G2";
+
+/// JSON Web Key of a BLS12-381 public key, and optionally of its secret key
+///
+/// `x` holds the base64url compressed `G2` public key, of `G2::serialize_compressed`, and `d` the
+/// base64url 32-byte big-endian secret key. Other members of a parsed JWK are ignored.
+///
+/// With `d`, `x` is the standard public key of `SecretKey::get_standard_public_key`, the one
+/// other libraries derive from `d`, not the MultiversX public key of `SecretKey::get_public_key`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Jwk {
+    pub kty: String,
+    pub crv: String,
+    pub x: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub d: Option<String>,
+}
+
+impl Jwk {
+    /// return the public key `x` of `self`
+    ///
+    /// If `self` also holds a secret key, `x` must be its standard public key.
+    pub fn public_key(&self) -> Result<G2, BlsError> {
+        if self.kty != JWK_KEY_TYPE || self.crv != JWK_CURVE_BLS12381_G2 {
+            return Err(BlsError::InvalidJwk);
+        }
+        let public_key =
+            G2::from_compressed(&decode_base64(&self.x)?).map_err(|_| BlsError::InvalidJwk)?;
+        if public_key.is_zero() || !public_key.is_valid_order() {
+            return Err(BlsError::InvalidJwk);
+        }
+
+        if let Some(d) = &self.d {
+            let secret_key =
+                secret_key_from_be_bytes(&decode_base64(d)?).map_err(|_| BlsError::InvalidJwk)?;
+            if secret_key.get_standard_public_key()? != public_key {
+                return Err(BlsError::PublicKeyMismatch);
+            }
+        }
+
+        Ok(public_key)
+    }
+
+    /// return the secret key of `self`
+    pub fn secret_key(&self) -> Result<SecretKey, BlsError> {
+        let d = self.d.as_ref().ok_or(BlsError::InvalidJwk)?;
+        self.public_key()?;
+
+        secret_key_from_be_bytes(&decode_base64(d)?).map_err(|_| BlsError::InvalidJwk)
+    }
+
+    /// return the RFC 7638 SHA-256 thumbprint of `self`
+    pub fn thumbprint(&self) -> Result<Vec<u8>, BlsError> {
+        self.public_key()?;
+        let members = format!(
+            r#"{{"crv":"{}","kty":"{}","x":"{}"}}"#,
+            self.crv, self.kty, self.x
+        );
+
+        Ok(Sha256::digest(members.as_bytes()).to_vec())
+    }
+
+    /// return the JWK of the JSON object `json`
+    pub fn from_json(json: &str) -> Result<Jwk, BlsError> {
+        serde_json::from_str(json).map_err(|_| BlsError::InvalidJwk)
+    }
+
+    /// return `self` as a JSON object
+    pub fn to_json(&self) -> Result<String, BlsError> {
+        serde_json::to_string(self).map_err(|_| BlsError::SerializeError)
+    }
+}
+
+impl G2 {
+    /// return the public JWK of `self`
+    pub fn to_jwk(&self) -> Result<Jwk, BlsError> {
+        Ok(Jwk {
+            kty: JWK_KEY_TYPE.to_string(),
+            crv: JWK_CURVE_BLS12381_G2.to_string(),
+            x: URL_SAFE_NO_PAD.encode(self.serialize_compressed()?),
+            d: None,
+        })
+    }
+}
+
+impl SecretKey {
+    /// return the private JWK of `self`, which includes its standard public key
+    pub fn to_jwk(&self) -> Result<Jwk, BlsError> {
+        Ok(Jwk {
+            d: Some(URL_SAFE_NO_PAD.encode(secret_key_to_be_bytes(self)?)),
+            ..self.get_standard_public_key()?.to_jwk()?
+        })
+    }
+}
+
+fn decode_base64(s: &str) -> Result<Vec<u8>, BlsError> {
+    URL_SAFE_NO_PAD.decode(s).map_err(|_| BlsError::InvalidJwk)
+}
@@ -0,0 +1,147 @@
+use hkdf::Hkdf;
+use sha2::{Digest, Sha256};
+
+use crate::bls_api::mclBnFr_setBigEndianMod;
+use crate::init::{init_library, INIT};
+use crate::secret_key::SecretKey;
+use crate::BlsError;
+
+/// minimum seed size accepted by `SecretKey::from_seed`, in bytes
+pub const MIN_SEED_SIZE: usize = 32;
+
+const KEYGEN_SALT: &[u8] = b"BLS-SIG-KEYGEN-SALT-";
+// ceil((3 * ceil(log2(r))) / 16)
+const KEYGEN_OKM_SIZE: usize = 48;
+const LAMPORT_CHUNK_SIZE: usize = 32;
+const LAMPORT_CHUNKS: usize = 255;
+const SECRET_KEY_SIZE: usize = 32;
+
+impl SecretKey {
+    /// return the master secret key derived from `seed` (EIP-2333 `derive_master_SK`)
+    /// * `seed` - at least `MIN_SEED_SIZE` bytes of entropy
+    pub fn from_seed(seed: &[u8]) -> Result<SecretKey, BlsError> {
+        if seed.len() < MIN_SEED_SIZE {
+            return Err(BlsError::BadSize);
+        }
+
+        hkdf_mod_r(seed)
+    }
+
+    /// return the child of `self` at `index` (EIP-2333 `derive_child_SK`)
+    pub fn derive_child(&self, index: u32) -> Result<SecretKey, BlsError> {
+        let compressed_lamport_pk = parent_to_lamport_public_key(self, index)?;
+
+        hkdf_mod_r(&compressed_lamport_pk)
+    }
+
+    /// return the descendant of `self` found by following `path`
+    /// * `path` - derivation path such as `m/12381/3600/0/0`, `self` being `m`
+    pub fn derive_path(&self, path: &str) -> Result<SecretKey, BlsError> {
+        parse_derivation_path(path)?
+            .into_iter()
+            .try_fold(*self, |key, index| key.derive_child(index))
+    }
+
+    /// return the secret key found by following `path` from the master key of `seed`
+    pub fn from_seed_and_path(seed: &[u8], path: &str) -> Result<SecretKey, BlsError> {
+        SecretKey::from_seed(seed)?.derive_path(path)
+    }
+}
+
+/// return the child indices of a derivation path such as `m/12381/3600/0/0`
+/// * `path` - must start with `m`, followed by `/`-separated decimal `u32` indices
+pub fn parse_derivation_path(path: &str) -> Result<Vec<u32>, BlsError> {
+    let mut nodes = path.split('/');
+    if nodes.next() != Some("m") {
+        return Err(BlsError::InvalidDerivationPath);
+    }
+
+    nodes
+        .map(|node| {
+            if node.is_empty() || !node.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(BlsError::InvalidDerivationPath);
+            }
+            node.parse::<u32>()
+                .map_err(|_| BlsError::InvalidDerivationPath)
+        })
+        .collect()
+}
+
+/// return the secret key given by `OS2IP(buf) mod r`
+pub(crate) fn secret_key_from_be_bytes_mod(buf: &[u8]) -> Result<SecretKey, BlsError> {
+    INIT.call_once(init_library);
+    let mut v = SecretKey::default();
+    if unsafe { mclBnFr_setBigEndianMod(&mut v, buf.as_ptr(), buf.len()) } != 0 {
+        return Err(BlsError::InternalError);
+    }
+
+    Ok(v)
+}
+
+/// return the non-zero secret key whose canonical 32-byte big-endian encoding is `buf`
+pub(crate) fn secret_key_from_be_bytes(buf: &[u8]) -> Result<SecretKey, BlsError> {
+    let secret_key = secret_key_from_be_bytes_mod(buf)?;
+    if secret_key.is_zero() || secret_key_to_be_bytes(&secret_key)? != buf {
+        return Err(BlsError::InvalidData);
+    }
+
+    Ok(secret_key)
+}
+
+/// return `I2OSP(secret_key, 32)`
+pub(crate) fn secret_key_to_be_bytes(secret_key: &SecretKey) -> Result<Vec<u8>, BlsError> {
+    let mut buf = secret_key.serialize()?;
+    if buf.len() != SECRET_KEY_SIZE {
+        return Err(BlsError::SerializeError);
+    }
+    buf.reverse();
+
+    Ok(buf)
+}
+
+fn hkdf_mod_r(ikm: &[u8]) -> Result<SecretKey, BlsError> {
+    let mut ikm = ikm.to_vec();
+    ikm.push(0);
+    let info = (KEYGEN_OKM_SIZE as u16).to_be_bytes();
+
+    let mut salt = Sha256::digest(KEYGEN_SALT);
+    loop {
+        let mut okm = [0u8; KEYGEN_OKM_SIZE];
+        Hkdf::<Sha256>::new(Some(&salt), &ikm)
+            .expand(&info, &mut okm)
+            .map_err(|_| BlsError::InternalError)?;
+
+        let secret_key = secret_key_from_be_bytes_mod(&okm)?;
+        if !secret_key.is_zero() {
+            return Ok(secret_key);
+        }
+        salt = Sha256::digest(salt);
+    }
+}
+
+fn ikm_to_lamport_secret_key(ikm: &[u8], salt: &[u8]) -> Result<Vec<u8>, BlsError> {
+    let mut okm = vec![0u8; LAMPORT_CHUNK_SIZE * LAMPORT_CHUNKS];
+    Hkdf::<Sha256>::new(Some(salt), ikm)
+        .expand(&[], &mut okm)
+        .map_err(|_| BlsError::InternalError)?;
+
+    Ok(okm)
+}
+
+fn parent_to_lamport_public_key(parent: &SecretKey, index: u32) -> Result<Vec<u8>, BlsError> {
+    let salt = index.to_be_bytes();
+    let ikm = secret_key_to_be_bytes(parent)?;
+    let not_ikm: Vec<u8> = ikm.iter().map(|b| !b).collect();
+
+    let mut hasher = Sha256::new();
+    for lamport_secret_key in [
+        ikm_to_lamport_secret_key(&ikm, &salt)?,
+        ikm_to_lamport_secret_key(&not_ikm, &salt)?,
+    ] {
+        for chunk in lamport_secret_key.chunks(LAMPORT_CHUNK_SIZE) {
+            hasher.update(Sha256::digest(chunk));
+        }
+    }
+
+    Ok(hasher.finalize().to_vec())
+}
@@ -0,0 +1,191 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::atomic_file::write_private_file_atomic;
+use crate::g1::G1;
+use crate::g2::G2;
+use crate::pem;
+use crate::secret_key::SecretKey;
+use crate::BlsError;
+
+struct KeyEntry {
+    secret_key: SecretKey,
+    public_key: G2,
+}
+
+/// secret keys of a multikey node, indexed by their serialized public key
+///
+/// The secret keys can only be read back through `export` and `export_secret_key`.
+#[derive(Default)]
+pub struct KeyRing {
+    keys: BTreeMap<Vec<u8>, KeyEntry>,
+    path: Option<PathBuf>,
+    checked_signing: bool,
+}
+
+impl fmt::Debug for KeyRing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyRing")
+            .field(
+                "public_keys",
+                &self.keys.keys().map(hex::encode).collect::<Vec<_>>(),
+            )
+            .field("path", &self.path)
+            .field("checked_signing", &self.checked_signing)
+            .finish()
+    }
+}
+
+impl KeyRing {
+    /// return an empty key ring
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// return the key ring holding the keys of the PEM blocks of `contents`
+    /// * `contents` - PEM data, as in `allValidatorsKeys.pem`
+    pub fn from_pem(contents: &str) -> Result<Self, BlsError> {
+        let mut key_ring = Self::new();
+        for secret_key in pem::decode_all(contents)? {
+            key_ring.add(secret_key)?;
+        }
+
+        Ok(key_ring)
+    }
+
+    /// return the key ring holding the keys of the PEM file at `path`
+    ///
+    /// The path is kept, so that the keys can later be read again with `reload`.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, BlsError> {
+        let contents =
+            fs::read_to_string(path.as_ref()).map_err(|err| BlsError::IoError(err.kind()))?;
+        let mut key_ring = Self::from_pem(&contents)?;
+        key_ring.path = Some(path.as_ref().to_path_buf());
+
+        Ok(key_ring)
+    }
+
+    /// return `self` signing with `SecretKey::sign_checked` if `checked_signing` is true
+    pub fn with_checked_signing(mut self, checked_signing: bool) -> Self {
+        self.checked_signing = checked_signing;
+        self
+    }
+
+    /// sign with `SecretKey::sign_checked`, against the public keys computed when the keys
+    /// were added, if `checked_signing` is true
+    pub fn set_checked_signing(&mut self, checked_signing: bool) {
+        self.checked_signing = checked_signing;
+    }
+
+    /// return true if the signatures of `self` are verified before they are returned
+    pub fn checked_signing(&self) -> bool {
+        self.checked_signing
+    }
+
+    /// replace the keys of `self` with the ones of the file it was loaded from
+    ///
+    /// `self` is left unchanged if the file cannot be read, and `BlsError::InvalidData` is
+    /// returned if `self` was not loaded from a file.
+    pub fn reload(&mut self) -> Result<(), BlsError> {
+        let path = self.path.clone().ok_or(BlsError::InvalidData)?;
+        *self = Self::load(path)?.with_checked_signing(self.checked_signing);
+
+        Ok(())
+    }
+
+    /// write the keys of `self` as PEM blocks to the file at `path`
+    ///
+    /// The file is written through a temporary file renamed over `path`, and is only readable
+    /// and writable by its owner on Unix.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), BlsError> {
+        let contents = self
+            .keys
+            .values()
+            .map(|entry| pem::encode(&entry.secret_key))
+            .collect::<Result<String, BlsError>>()?;
+
+        write_private_file_atomic(path.as_ref(), contents.as_bytes())
+    }
+
+    /// add `secret_key` and return its public key
+    pub fn add(&mut self, secret_key: SecretKey) -> Result<G2, BlsError> {
+        if secret_key.is_zero() || !secret_key.is_valid() {
+            return Err(BlsError::InvalidData);
+        }
+
+        let public_key = secret_key.get_public_key();
+        let index = public_key.serialize()?;
+        if self.keys.contains_key(&index) {
+            return Err(BlsError::DuplicatePublicKey);
+        }
+
+        self.keys.insert(
+            index,
+            KeyEntry {
+                secret_key,
+                public_key,
+            },
+        );
+
+        Ok(public_key)
+    }
+
+    /// remove the key of `public_key`
+    pub fn remove(&mut self, public_key: &G2) -> Result<(), BlsError> {
+        self.keys
+            .remove(&public_key.serialize()?)
+            .map(|_| ())
+            .ok_or(BlsError::UnknownPublicKey)
+    }
+
+    /// return true if `self` holds the key of `public_key`
+    pub fn contains(&self, public_key: &G2) -> bool {
+        self.entry(public_key).is_ok()
+    }
+
+    /// return the public keys of `self`, ordered by their serialization
+    pub fn public_keys(&self) -> Vec<G2> {
+        self.keys.values().map(|entry| entry.public_key).collect()
+    }
+
+    /// return the number of keys of `self`
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// return true if `self` holds no key
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// return the signature of `msg` by the key of `public_key`
+    ///
+    /// With checked signing, returns `BlsError::SignatureCheckFailed` if the signature does
+    /// not verify against the public key computed when the key was added.
+    pub fn sign(&self, public_key: &G2, msg: &[u8]) -> Result<G1, BlsError> {
+        let entry = self.entry(public_key)?;
+        if self.checked_signing {
+            return entry.secret_key.sign_checked(&entry.public_key, msg);
+        }
+
+        Ok(entry.secret_key.sign(msg))
+    }
+
+    /// return the PEM block of the key of `public_key`
+    pub fn export(&self, public_key: &G2) -> Result<String, BlsError> {
+        pem::encode(&self.entry(public_key)?.secret_key)
+    }
+
+    /// return a copy of the secret key of `public_key`
+    pub fn export_secret_key(&self, public_key: &G2) -> Result<SecretKey, BlsError> {
+        Ok(self.entry(public_key)?.secret_key)
+    }
+
+    fn entry(&self, public_key: &G2) -> Result<&KeyEntry, BlsError> {
+        self.keys
+            .get(&public_key.serialize()?)
+            .ok_or(BlsError::UnknownPublicKey)
+    }
+}
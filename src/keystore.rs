@@ -0,0 +1,371 @@
+use std::fs;
+use std::path::Path;
+
+use aes::Aes128;
+use ctr::cipher::{KeyIvInit, StreamCipher};
+use ctr::Ctr128BE;
+use hmac::Hmac;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+use unicode_normalization::UnicodeNormalization;
+use uuid::Uuid;
+
+use crate::atomic_file::write_private_file_atomic;
+use crate::g2::G2;
+use crate::key_derivation::{secret_key_from_be_bytes, secret_key_to_be_bytes};
+use crate::secret_key::SecretKey;
+use crate::BlsError;
+
+/// version of the keystores written and accepted by `Keystore`
+pub const KEYSTORE_VERSION: u32 = 4;
+
+const KDF_SCRYPT: &str = "scrypt";
+const KDF_PBKDF2: &str = "pbkdf2";
+const PRF_HMAC_SHA256: &str = "hmac-sha256";
+const CHECKSUM_SHA256: &str = "sha256";
+const CIPHER_AES_128_CTR: &str = "aes-128-ctr";
+
+const DECRYPTION_KEY_SIZE: usize = 32;
+const SALT_SIZE: usize = 32;
+const IV_SIZE: usize = 16;
+const SECRET_SIZE: usize = 32;
+
+// maximum key derivation parameters accepted, bounding the memory and time spent on a keystore
+// and keeping `r * p` of scrypt far from overflowing
+const MAX_SCRYPT_N: u32 = 1 << 20;
+const MAX_SCRYPT_R: u32 = 32;
+const MAX_SCRYPT_P: u32 = 16;
+const MAX_PBKDF2_C: u32 = 1 << 24;
+
+/// key derivation function turning the password of a keystore into its decryption key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeystoreKdf {
+    /// scrypt, with the cost `n` being a power of two up to 2^20, `r` up to 32 and `p` up to 16
+    Scrypt { n: u32, r: u32, p: u32 },
+    /// PBKDF2 with HMAC-SHA256 and `c` iterations, up to 2^24
+    Pbkdf2 { c: u32 },
+}
+
+impl Default for KeystoreKdf {
+    /// scrypt with the EIP-2335 reference parameters
+    fn default() -> Self {
+        KeystoreKdf::Scrypt {
+            n: 262144,
+            r: 8,
+            p: 1,
+        }
+    }
+}
+
+impl KeystoreKdf {
+    fn scrypt_params(n: u32, r: u32, p: u32) -> Result<scrypt::Params, BlsError> {
+        if n < 2 || !n.is_power_of_two() || n > MAX_SCRYPT_N || r > MAX_SCRYPT_R || p > MAX_SCRYPT_P
+        {
+            return Err(BlsError::InvalidKeystore);
+        }
+
+        scrypt::Params::new(n.trailing_zeros() as u8, r, p).map_err(|_| BlsError::InvalidKeystore)
+    }
+
+    fn validate(&self) -> Result<(), BlsError> {
+        match *self {
+            KeystoreKdf::Scrypt { n, r, p } => Self::scrypt_params(n, r, p).map(|_| ()),
+            KeystoreKdf::Pbkdf2 { c } if c > 0 && c <= MAX_PBKDF2_C => Ok(()),
+            KeystoreKdf::Pbkdf2 { .. } => Err(BlsError::InvalidKeystore),
+        }
+    }
+
+    fn derive_key(&self, password: &[u8], salt: &[u8]) -> Result<Vec<u8>, BlsError> {
+        let mut key = vec![0u8; DECRYPTION_KEY_SIZE];
+        match *self {
+            KeystoreKdf::Scrypt { n, r, p } => {
+                scrypt::scrypt(password, salt, &Self::scrypt_params(n, r, p)?, &mut key)
+                    .map_err(|_| BlsError::InvalidKeystore)?
+            }
+            KeystoreKdf::Pbkdf2 { c } => {
+                pbkdf2::pbkdf2::<Hmac<Sha256>>(password, salt, c, &mut key)
+                    .map_err(|_| BlsError::InvalidKeystore)?
+            }
+        }
+
+        Ok(key)
+    }
+
+    fn to_module(self, salt: &[u8]) -> KeystoreModule {
+        let (function, params) = match self {
+            KeystoreKdf::Scrypt { n, r, p } => (
+                KDF_SCRYPT,
+                serde_json::json!({
+                    "dklen": DECRYPTION_KEY_SIZE,
+                    "n": n,
+                    "r": r,
+                    "p": p,
+                    "salt": hex::encode(salt),
+                }),
+            ),
+            KeystoreKdf::Pbkdf2 { c } => (
+                KDF_PBKDF2,
+                serde_json::json!({
+                    "dklen": DECRYPTION_KEY_SIZE,
+                    "c": c,
+                    "prf": PRF_HMAC_SHA256,
+                    "salt": hex::encode(salt),
+                }),
+            ),
+        };
+
+        KeystoreModule {
+            function: function.to_string(),
+            params: match params {
+                Value::Object(params) => params,
+                _ => Map::new(),
+            },
+            message: String::new(),
+        }
+    }
+
+    /// return the function and salt described by `module`
+    fn from_module(module: &KeystoreModule) -> Result<(KeystoreKdf, Vec<u8>), BlsError> {
+        if !module.message.is_empty() {
+            return Err(BlsError::InvalidKeystore);
+        }
+
+        let (kdf, dklen, salt) = match module.function.as_str() {
+            KDF_SCRYPT => {
+                let params: ScryptParams = parse_params(&module.params)?;
+                let kdf = KeystoreKdf::Scrypt {
+                    n: params.n,
+                    r: params.r,
+                    p: params.p,
+                };
+                (kdf, params.dklen, params.salt)
+            }
+            KDF_PBKDF2 => {
+                let params: Pbkdf2Params = parse_params(&module.params)?;
+                if params.prf != PRF_HMAC_SHA256 {
+                    return Err(BlsError::InvalidKeystore);
+                }
+                (
+                    KeystoreKdf::Pbkdf2 { c: params.c },
+                    params.dklen,
+                    params.salt,
+                )
+            }
+            _ => return Err(BlsError::InvalidKeystore),
+        };
+
+        kdf.validate()?;
+        let salt = decode_hex(&salt)?;
+        if dklen != DECRYPTION_KEY_SIZE || salt.is_empty() {
+            return Err(BlsError::InvalidKeystore);
+        }
+
+        Ok((kdf, salt))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ScryptParams {
+    dklen: usize,
+    n: u32,
+    r: u32,
+    p: u32,
+    salt: String,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Pbkdf2Params {
+    dklen: usize,
+    c: u32,
+    prf: String,
+    salt: String,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CipherParams {
+    iv: String,
+}
+
+/// one step (`kdf`, `checksum` or `cipher`) of the protection of a keystore
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct KeystoreModule {
+    pub function: String,
+    pub params: Map<String, Value>,
+    pub message: String,
+}
+
+/// `crypto` section of a keystore
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct KeystoreCrypto {
+    pub kdf: KeystoreModule,
+    pub checksum: KeystoreModule,
+    pub cipher: KeystoreModule,
+}
+
+/// password-encrypted secret key, in the EIP-2335 JSON format
+///
+/// `pubkey` is the hexadecimal serialized `G2` public key of the secret key.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Keystore {
+    pub crypto: KeystoreCrypto,
+    #[serde(default)]
+    pub description: String,
+    pub pubkey: String,
+    pub path: String,
+    pub uuid: String,
+    pub version: u32,
+}
+
+impl Keystore {
+    /// return the keystore of `secret_key`, encrypted under `password`
+    /// * `kdf` - key derivation function, `KeystoreKdf::default()` unless a lighter one is needed
+    pub fn encrypt(
+        secret_key: &SecretKey,
+        password: &str,
+        kdf: KeystoreKdf,
+    ) -> Result<Keystore, BlsError> {
+        kdf.validate()?;
+
+        let mut salt = [0u8; SALT_SIZE];
+        let mut iv = [0u8; IV_SIZE];
+        getrandom::fill(&mut salt).map_err(|_| BlsError::InternalError)?;
+        getrandom::fill(&mut iv).map_err(|_| BlsError::InternalError)?;
+
+        let decryption_key = kdf.derive_key(&process_password(password), &salt)?;
+        let mut cipher_message = secret_key_to_be_bytes(secret_key)?;
+        apply_cipher(&decryption_key, &iv, &mut cipher_message)?;
+
+        Ok(Keystore {
+            crypto: KeystoreCrypto {
+                kdf: kdf.to_module(&salt),
+                checksum: KeystoreModule {
+                    function: CHECKSUM_SHA256.to_string(),
+                    params: Map::new(),
+                    message: hex::encode(checksum(&decryption_key, &cipher_message)),
+                },
+                cipher: KeystoreModule {
+                    function: CIPHER_AES_128_CTR.to_string(),
+                    params: Map::from_iter([("iv".to_string(), Value::from(hex::encode(iv)))]),
+                    message: hex::encode(&cipher_message),
+                },
+            },
+            description: String::new(),
+            pubkey: hex::encode(secret_key.get_public_key().serialize()?),
+            path: String::new(),
+            uuid: Uuid::new_v4().to_string(),
+            version: KEYSTORE_VERSION,
+        })
+    }
+
+    /// return the secret key of `self`
+    /// * `password` - password given to `encrypt`
+    pub fn decrypt(&self, password: &str) -> Result<SecretKey, BlsError> {
+        if self.version != KEYSTORE_VERSION || Uuid::parse_str(&self.uuid).is_err() {
+            return Err(BlsError::InvalidKeystore);
+        }
+
+        let (kdf, salt) = KeystoreKdf::from_module(&self.crypto.kdf)?;
+
+        let checksum_module = &self.crypto.checksum;
+        if checksum_module.function != CHECKSUM_SHA256 || !checksum_module.params.is_empty() {
+            return Err(BlsError::InvalidKeystore);
+        }
+        let expected_checksum = decode_hex(&checksum_module.message)?;
+
+        let cipher_module = &self.crypto.cipher;
+        if cipher_module.function != CIPHER_AES_128_CTR {
+            return Err(BlsError::InvalidKeystore);
+        }
+        let iv = decode_hex(&parse_params::<CipherParams>(&cipher_module.params)?.iv)?;
+        let mut secret = decode_hex(&cipher_module.message)?;
+        if iv.len() != IV_SIZE
+            || secret.len() != SECRET_SIZE
+            || expected_checksum.len() != Sha256::output_size()
+        {
+            return Err(BlsError::InvalidKeystore);
+        }
+
+        let decryption_key = kdf.derive_key(&process_password(password), &salt)?;
+        if checksum(&decryption_key, &secret) != expected_checksum {
+            return Err(BlsError::InvalidPassword);
+        }
+        apply_cipher(&decryption_key, &iv, &mut secret)?;
+
+        let secret_key =
+            secret_key_from_be_bytes(&secret).map_err(|_| BlsError::InvalidKeystore)?;
+        if !self.pubkey.is_empty() && self.public_key()? != secret_key.get_public_key() {
+            return Err(BlsError::PublicKeyMismatch);
+        }
+
+        Ok(secret_key)
+    }
+
+    /// return the public key recorded in `self`
+    pub fn public_key(&self) -> Result<G2, BlsError> {
+        G2::from_serialized(&decode_hex(&self.pubkey)?)
+    }
+
+    /// return the keystore written in `json`
+    pub fn from_json(json: &str) -> Result<Keystore, BlsError> {
+        serde_json::from_str(json).map_err(|_| BlsError::InvalidKeystore)
+    }
+
+    /// return `self` as pretty-printed JSON
+    pub fn to_json(&self) -> Result<String, BlsError> {
+        serde_json::to_string_pretty(self).map_err(|_| BlsError::SerializeError)
+    }
+
+    /// return the keystore of the file at `path`
+    pub fn read_file<P: AsRef<Path>>(path: P) -> Result<Keystore, BlsError> {
+        let json = fs::read_to_string(path).map_err(|err| BlsError::IoError(err.kind()))?;
+
+        Keystore::from_json(&json)
+    }
+
+    /// write `self` to the file at `path`, readable and writable by its owner only on Unix
+    pub fn write_file<P: AsRef<Path>>(&self, path: P) -> Result<(), BlsError> {
+        write_private_file_atomic(path.as_ref(), self.to_json()?.as_bytes())
+    }
+}
+
+/// return the NFKD normalized `password`, stripped of its control codes
+fn process_password(password: &str) -> Vec<u8> {
+    password
+        .nfkd()
+        .filter(|c| !c.is_control())
+        .collect::<String>()
+        .into_bytes()
+}
+
+fn checksum(decryption_key: &[u8], cipher_message: &[u8]) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+    hasher.update(&decryption_key[16..32]);
+    hasher.update(cipher_message);
+
+    hasher.finalize().to_vec()
+}
+
+fn apply_cipher(decryption_key: &[u8], iv: &[u8], buf: &mut [u8]) -> Result<(), BlsError> {
+    let mut cipher = Ctr128BE::<Aes128>::new_from_slices(&decryption_key[..16], iv)
+        .map_err(|_| BlsError::InvalidKeystore)?;
+    cipher.apply_keystream(buf);
+
+    Ok(())
+}
+
+fn parse_params<T: serde::de::DeserializeOwned>(
+    params: &Map<String, Value>,
+) -> Result<T, BlsError> {
+    serde_json::from_value(Value::Object(params.clone())).map_err(|_| BlsError::InvalidKeystore)
+}
+
+fn decode_hex(s: &str) -> Result<Vec<u8>, BlsError> {
+    hex::decode(s).map_err(|_| BlsError::InvalidKeystore)
+}
@@ -1,14 +1,71 @@
+// Modules defining types or methods are private, their public items being re-exported at the
+// root. Only the modules of free functions named after their module, as `pem::decode` or
+// `vm_hooks::verify_bls`, are public.
+mod atomic_file;
 mod bls_api;
+mod compressed;
 mod constants;
+mod cose_key;
+mod did_key;
+mod equivocation;
 mod error;
 mod g1;
 mod g2;
 mod gt;
+mod header_proof;
 mod init;
+mod invalid_shares;
+mod jwk;
+mod key_derivation;
+mod key_ring;
+mod keystore;
+mod mnemonic;
+pub mod multisig;
+pub mod pem;
+mod pkcs8;
+pub mod randomness;
+mod remote_signer;
 mod secret_key;
+mod signature_collector;
+mod signer;
+mod signing_protection;
+mod staking;
+mod threshold;
+mod validator_set;
+pub mod vm_hooks;
+mod vrf;
 
+pub use compressed::{G1_COMPRESSED_SIZE, G2_COMPRESSED_SIZE};
+pub use cose_key::{COSE_CURVE_BLS12381_G2, COSE_KEY_TYPE_OKP};
+pub use did_key::{
+    resolve_did_key, DidDocument, VerificationMethod, DID_KEY_PREFIX, MULTICODEC_BLS12_381_G1_PUB,
+    MULTICODEC_BLS12_381_G2_PUB,
+};
+pub use equivocation::{Equivocation, Slot};
 pub use error::BlsError;
 pub use g1::G1;
 pub use g2::G2;
 pub use gt::GT;
+pub use header_proof::{HeaderProof, HEADER_HASH_SIZE};
+pub use invalid_shares::find_invalid_shares;
+pub use jwk::{Jwk, JWK_CURVE_BLS12381_G2, JWK_KEY_TYPE};
+pub use key_derivation::{parse_derivation_path, MIN_SEED_SIZE};
+pub use key_ring::KeyRing;
+pub use keystore::{Keystore, KeystoreCrypto, KeystoreKdf, KeystoreModule, KEYSTORE_VERSION};
+pub use mnemonic::{generate_mnemonic, parse_mnemonic, Mnemonic, SECRET_KEY_MNEMONIC_WORDS};
+pub use pkcs8::{OID_BLS12_381_G2, PKCS8_PRIVATE_KEY_LABEL, PKCS8_PUBLIC_KEY_LABEL};
+pub use remote_signer::{
+    RemoteSigner, RemoteSignerServer, RemoteSignerToken, REMOTE_SIGNER_MAX_BODY_SIZE,
+    REMOTE_SIGNER_MAX_CONNECTIONS, REMOTE_SIGNER_TOKEN_SIZE,
+};
 pub use secret_key::SecretKey;
+pub use signature_collector::{ShareVerification, SignatureCollector};
+pub use signer::Signer;
+pub use signing_protection::{
+    SignedSlot, SigningProtection, SigningProtectionInterchange, Watermark,
+    SIGNING_PROTECTION_VERSION,
+};
+pub use staking::{decode_address, encode_address, ADDRESS_HRP, ADDRESS_SIZE};
+pub use threshold::{recover_signature, split_secret_key, ThresholdShare};
+pub use validator_set::ValidatorSet;
+pub use vrf::{VRF_DST, VRF_OUTPUT_SIZE};
@@ -0,0 +1,62 @@
+pub use bip39::Mnemonic;
+
+use crate::key_derivation::{secret_key_from_be_bytes_mod, secret_key_to_be_bytes};
+use crate::secret_key::SecretKey;
+use crate::BlsError;
+
+/// number of words of the mnemonic returned by `SecretKey::to_entropy_mnemonic`
+pub const SECRET_KEY_MNEMONIC_WORDS: usize = 24;
+
+/// return a new random English mnemonic
+/// * `word_count` - 12, 15, 18, 21 or 24
+pub fn generate_mnemonic(word_count: usize) -> Result<Mnemonic, BlsError> {
+    Mnemonic::generate(word_count).map_err(|_| BlsError::InvalidMnemonic)
+}
+
+/// return the mnemonic written in `phrase`, after validating its words and checksum
+pub fn parse_mnemonic(phrase: &str) -> Result<Mnemonic, BlsError> {
+    Mnemonic::parse(phrase).map_err(|_| BlsError::InvalidMnemonic)
+}
+
+impl SecretKey {
+    /// return the master secret key of the BIP-39 seed of `mnemonic`
+    /// * `passphrase` - optional BIP-39 passphrase, empty if unused
+    ///
+    /// This is not the inverse of `to_entropy_mnemonic`, whose mnemonics are restored with
+    /// `from_entropy_mnemonic`.
+    pub fn from_mnemonic(mnemonic: &Mnemonic, passphrase: &str) -> Result<SecretKey, BlsError> {
+        SecretKey::from_seed(&mnemonic.to_seed(passphrase))
+    }
+
+    /// return the secret key found by following `path` from the master key of `mnemonic`
+    pub fn from_mnemonic_and_path(
+        mnemonic: &Mnemonic,
+        passphrase: &str,
+        path: &str,
+    ) -> Result<SecretKey, BlsError> {
+        SecretKey::from_mnemonic(mnemonic, passphrase)?.derive_path(path)
+    }
+
+    /// return a 24-word mnemonic whose entropy is `self`
+    ///
+    /// The mnemonic is a backup of `self` only: it is restored with `from_entropy_mnemonic`,
+    /// while `from_mnemonic` derives another key from it.
+    pub fn to_entropy_mnemonic(&self) -> Result<Mnemonic, BlsError> {
+        Mnemonic::from_entropy(&secret_key_to_be_bytes(self)?).map_err(|_| BlsError::InternalError)
+    }
+
+    /// return the secret key written by `to_entropy_mnemonic`
+    pub fn from_entropy_mnemonic(mnemonic: &Mnemonic) -> Result<SecretKey, BlsError> {
+        if mnemonic.word_count() != SECRET_KEY_MNEMONIC_WORDS {
+            return Err(BlsError::InvalidMnemonic);
+        }
+
+        let entropy = mnemonic.to_entropy();
+        let secret_key = secret_key_from_be_bytes_mod(&entropy)?;
+        if secret_key.is_zero() || secret_key_to_be_bytes(&secret_key)? != entropy {
+            return Err(BlsError::InvalidMnemonic);
+        }
+
+        Ok(secret_key)
+    }
+}
@@ -0,0 +1,80 @@
+//! BLS multisigner relying on the knowledge of secret key (KOSK) of every signer, as
+//! `BlsMultiSignerKOSK` of mx-chain-crypto-go.
+//!
+//! Shares are plain BLS signatures and are aggregated by addition, which is only safe
+//! against rogue key attacks when each public key comes with a proof of possession, as is the
+//! case for MultiversX validators.
+
+use super::{public_key_from_bytes, signature_from_bytes};
+use crate::g1::G1;
+use crate::signer::Signer;
+use crate::BlsError;
+
+/// return the serialized signature share of `msg` by `signer`
+pub fn create_signature_share<S: Signer + ?Sized>(
+    signer: &S,
+    msg: &[u8],
+) -> Result<Vec<u8>, BlsError> {
+    if msg.is_empty() {
+        return Err(BlsError::InvalidData);
+    }
+
+    signer.sign(msg)?.serialize()
+}
+
+/// check the signature share `signature` of `msg` by `public_key`
+/// * `public_key` - serialized `G2` public key of the signer
+/// * `signature` - serialized `G1` signature share
+pub fn verify_signature_share(
+    public_key: &[u8],
+    msg: &[u8],
+    signature: &[u8],
+) -> Result<(), BlsError> {
+    if msg.is_empty() {
+        return Err(BlsError::InvalidData);
+    }
+
+    let public_key = public_key_from_bytes(public_key)?;
+    if !signature_from_bytes(signature)?.verify(public_key, msg) {
+        return Err(BlsError::InvalidSignature);
+    }
+
+    Ok(())
+}
+
+/// return the serialized aggregation of the signature shares `signatures`
+pub fn aggregate_signatures<S: AsRef<[u8]>>(signatures: &[S]) -> Result<Vec<u8>, BlsError> {
+    if signatures.is_empty() {
+        return Err(BlsError::InvalidData);
+    }
+
+    let signatures = signatures
+        .iter()
+        .map(|signature| signature_from_bytes(signature.as_ref()))
+        .collect::<Result<Vec<G1>, BlsError>>()?;
+    let mut aggregated = G1::default();
+    aggregated.aggregate(&signatures);
+
+    aggregated.serialize()
+}
+
+/// check the aggregated signature `signature` of `msg` by all of `public_keys`
+pub fn verify_aggregated_signature<K: AsRef<[u8]>>(
+    public_keys: &[K],
+    msg: &[u8],
+    signature: &[u8],
+) -> Result<(), BlsError> {
+    if public_keys.is_empty() || msg.is_empty() {
+        return Err(BlsError::InvalidData);
+    }
+
+    let public_keys = public_keys
+        .iter()
+        .map(|public_key| public_key_from_bytes(public_key.as_ref()))
+        .collect::<Result<Vec<_>, BlsError>>()?;
+    if !signature_from_bytes(signature)?.fast_aggregate_verify(&public_keys, msg) {
+        return Err(BlsError::InvalidSignature);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+//! BLS multisigners.
+//!
+//! `kosk` is the KOSK multisigner of mx-chain-crypto-go, checked against vectors of the Go
+//! implementation. `non_kosk` weights keys and shares by hashed coefficients, and is not known
+//! to match the Go implementation.
+//!
+//! `kosk` handles public keys, signature shares and aggregated signatures as the serialized `G2`
+//! and `G1` points exchanged by the nodes, while `non_kosk` works on `G2` and `G1` directly.
+
+pub mod kosk;
+pub mod non_kosk;
+
+use crate::g1::G1;
+use crate::g2::G2;
+use crate::BlsError;
+
+/// return the public key of `buf`, rejecting the zero point and points outside the subgroup
+fn public_key_from_bytes(buf: &[u8]) -> Result<G2, BlsError> {
+    let public_key = G2::from_serialized(buf)?;
+    if public_key.is_zero() || !public_key.is_valid_order() || !public_key.is_valid() {
+        return Err(BlsError::InvalidData);
+    }
+
+    Ok(public_key)
+}
+
+/// return the signature of `buf`, rejecting the zero point and points outside the subgroup
+fn signature_from_bytes(buf: &[u8]) -> Result<G1, BlsError> {
+    let signature = G1::from_serialized(buf)?;
+    if signature.is_zero() || !signature.is_valid_order() || !signature.is_valid() {
+        return Err(BlsError::InvalidData);
+    }
+
+    Ok(signature)
+}
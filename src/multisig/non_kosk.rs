@@ -0,0 +1,103 @@
+//! BLS multisigner resistant to rogue key attacks without proofs of possession.
+//!
+//! Each public key and signature share is weighted by the coefficient
+//! `t_i = blake2b-128(pk_i || pk_1 || ... || pk_n)`, read as a big-endian scalar, where
+//! `pk_1, ..., pk_n` are the serialized public keys of the signers in signing order. Shares
+//! are created and verified as in `kosk`.
+//!
+//! It is not known to match the non-KOSK multisigner of mx-chain-crypto-go: the test vectors
+//! were computed by other implementations of the scheme as described here, not by the Go
+//! implementation.
+
+use blake2::{Blake2b128, Digest};
+
+pub use super::kosk::{create_signature_share, verify_signature_share};
+use crate::g1::G1;
+use crate::g2::G2;
+use crate::secret_key::SecretKey;
+use crate::BlsError;
+
+const COEFFICIENT_SIZE: usize = 32;
+
+/// return the coefficients of `public_keys`, in the same order
+pub fn coefficients(public_keys: &[G2]) -> Result<Vec<SecretKey>, BlsError> {
+    if public_keys.is_empty() {
+        return Err(BlsError::InvalidData);
+    }
+
+    let serialized = public_keys
+        .iter()
+        .map(|public_key| public_key.serialize())
+        .collect::<Result<Vec<_>, BlsError>>()?;
+    let concatenated = serialized.concat();
+
+    serialized
+        .iter()
+        .map(|public_key| {
+            let hash = Blake2b128::new()
+                .chain_update(public_key)
+                .chain_update(&concatenated)
+                .finalize();
+            // the serialization of `SecretKey` is little-endian
+            let mut buf = [0u8; COEFFICIENT_SIZE];
+            buf[..hash.len()].copy_from_slice(&hash);
+            buf[..hash.len()].reverse();
+            SecretKey::from_serialized(&buf)
+        })
+        .collect()
+}
+
+/// return the sum of `public_keys`, each weighted by its coefficient
+pub fn aggregate_public_keys(public_keys: &[G2]) -> Result<G2, BlsError> {
+    let mut aggregated = G2::default();
+    for (public_key, coefficient) in public_keys.iter().zip(coefficients(public_keys)?) {
+        let mut weighted = *public_key;
+        weighted.mul_assign(&coefficient);
+        aggregated.add_assign(weighted);
+    }
+
+    Ok(aggregated)
+}
+
+/// return the sum of `signatures`, each weighted by the coefficient of its signer
+/// * `public_keys` - public keys of the signers, `signatures[i]` being the share of
+///   `public_keys[i]`
+pub fn aggregate_signatures(public_keys: &[G2], signatures: &[G1]) -> Result<G1, BlsError> {
+    if public_keys.len() != signatures.len() {
+        return Err(BlsError::BadSize);
+    }
+
+    let mut aggregated = G1::default();
+    for (signature, coefficient) in signatures.iter().zip(coefficients(public_keys)?) {
+        let mut weighted = *signature;
+        weighted.mul_assign(&coefficient);
+        aggregated.add_assign(weighted);
+    }
+
+    Ok(aggregated)
+}
+
+/// check the aggregated signature `signature` of `msg` by all of `public_keys`, in signing order
+pub fn verify_aggregated_signature(
+    public_keys: &[G2],
+    msg: &[u8],
+    signature: &G1,
+) -> Result<(), BlsError> {
+    if msg.is_empty() {
+        return Err(BlsError::InvalidData);
+    }
+    if public_keys
+        .iter()
+        .any(|public_key| public_key.is_zero() || !public_key.is_valid_order())
+        || signature.is_zero()
+        || !signature.is_valid_order()
+    {
+        return Err(BlsError::InvalidData);
+    }
+
+    if !signature.verify(aggregate_public_keys(public_keys)?, msg) {
+        return Err(BlsError::InvalidSignature);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+//! Randomness chain of MultiversX consensus rounds.
+//!
+//! The leader of a round signs the random seed of the previous header, and the serialized
+//! signature is the random seed of its header. BLS signatures being unique, the leader cannot
+//! bias the seed, and anyone holding the leader public keys can audit the chain.
+
+use crate::g1::G1;
+use crate::g2::G2;
+use crate::signer::Signer;
+use crate::BlsError;
+
+/// return the random seed of the header proposed by `leader` after the seed `prev_rand_seed`
+pub fn next_rand_seed<S: Signer + ?Sized>(
+    leader: &S,
+    prev_rand_seed: &[u8],
+) -> Result<Vec<u8>, BlsError> {
+    if prev_rand_seed.is_empty() {
+        return Err(BlsError::InvalidData);
+    }
+
+    leader.sign(prev_rand_seed)?.serialize()
+}
+
+/// check that `rand_seed` is the random seed proposed by `leader` after the seed
+/// `prev_rand_seed`
+///
+/// Returns `BlsError::InvalidData` if a seed is empty or `rand_seed` is not a valid point, and
+/// `BlsError::InvalidSignature` if it is not the signature of `prev_rand_seed` by `leader`.
+pub fn verify_rand_seed(
+    leader: &G2,
+    prev_rand_seed: &[u8],
+    rand_seed: &[u8],
+) -> Result<(), BlsError> {
+    if prev_rand_seed.is_empty() || leader.is_zero() || !leader.is_valid_order() {
+        return Err(BlsError::InvalidData);
+    }
+    let signature = G1::from_serialized(rand_seed)?;
+    if signature.is_zero() || !signature.is_valid_order() {
+        return Err(BlsError::InvalidData);
+    }
+    if !signature.verify(*leader, prev_rand_seed) {
+        return Err(BlsError::InvalidSignature);
+    }
+
+    Ok(())
+}
+
+/// check the chain of random seeds `rand_seeds` following `start_rand_seed`, `rand_seeds[i]`
+/// being proposed by `leaders[i]`, and return the last random seed
+///
+/// Returns `BlsError::BadSize` if there is not one leader per seed, and the error of
+/// `verify_rand_seed` for the first seed which does not verify.
+pub fn verify_rand_seed_chain<S: AsRef<[u8]>>(
+    start_rand_seed: &[u8],
+    leaders: &[G2],
+    rand_seeds: &[S],
+) -> Result<Vec<u8>, BlsError> {
+    if leaders.len() != rand_seeds.len() {
+        return Err(BlsError::BadSize);
+    }
+
+    let mut prev_rand_seed = start_rand_seed;
+    for (leader, rand_seed) in leaders.iter().zip(rand_seeds) {
+        verify_rand_seed(leader, prev_rand_seed, rand_seed.as_ref())?;
+        prev_rand_seed = rand_seed.as_ref();
+    }
+
+    Ok(prev_rand_seed.to_vec())
+}
@@ -0,0 +1,608 @@
+//! Remote signing over a local HTTP/JSON API.
+//!
+//! `RemoteSignerServer` serves the keys of a `KeyRing`, so that they never leave the signer
+//! host, and `RemoteSigner` is the `Signer` of one of these keys. The API has three endpoints:
+//!
+//! - `GET /keys` returns `{"publicKeys": [...]}`, the hex serialized public keys;
+//! - `POST /sign` with `{"publicKey": ..., "message": ...}` returns `{"signature": ...}`, all
+//!   hex encoded, or the status 404 for an unknown key;
+//! - `POST /sign_batch` with `{"publicKey": ..., "messages": [...]}` returns
+//!   `{"signatures": [...]}`, in the order of the messages.
+//!
+//! Each connection carries one request, and connections are served concurrently. Requests are
+//! authenticated by the `Authorization: Bearer` header holding the `RemoteSignerToken` of the
+//! server, read from a file only readable by its owner, or refused with the status 401. The
+//! `Host` header must be the address the connection was accepted on, and requests with a body
+//! must have the `Content-Type: application/json` header, so that web pages resolving their host
+//! name to the server cannot reach it. `RemoteSignerServer::bind` only listens on loopback
+//! addresses, and `RemoteSignerServer::bind_remote` should only be exposed to trusted hosts, as
+//! the token is sent in clear text.
+//!
+//! The server signs any message it is sent. It does not go through a `SigningProtection`, so
+//! slashing protection is left to the clients, which should sign through a `SigningProtection`
+//! wrapping their `RemoteSigner`.
+
+use std::fmt;
+use std::fs;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::atomic_file::write_private_file_atomic;
+use crate::g1::G1;
+use crate::g2::G2;
+use crate::key_ring::KeyRing;
+use crate::signer::Signer;
+use crate::BlsError;
+
+/// maximum size in bytes of the requests and responses
+pub const REMOTE_SIGNER_MAX_BODY_SIZE: usize = 1 << 20;
+
+/// maximum number of connections served at once, further connections being closed
+pub const REMOTE_SIGNER_MAX_CONNECTIONS: usize = 64;
+
+/// size in bytes of a `RemoteSignerToken`, stored hex encoded
+pub const REMOTE_SIGNER_TOKEN_SIZE: usize = 32;
+
+const IO_TIMEOUT: Duration = Duration::from_secs(10);
+// deadline of the server to read a request, so that idle connections are closed quickly
+const SERVER_IO_TIMEOUT: Duration = Duration::from_secs(2);
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct KeysResponse {
+    public_keys: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SignRequest {
+    public_key: String,
+    message: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SignResponse {
+    signature: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SignBatchRequest {
+    public_key: String,
+    messages: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SignBatchResponse {
+    signatures: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+/// bearer token authenticating the requests to a `RemoteSignerServer`
+///
+/// The token is a secret: its `Debug` output is redacted and it is compared in constant time.
+#[derive(Clone)]
+pub struct RemoteSignerToken(String);
+
+impl RemoteSignerToken {
+    /// return a new random token
+    pub fn generate() -> Result<Self, BlsError> {
+        let mut buf = [0u8; REMOTE_SIGNER_TOKEN_SIZE];
+        getrandom::fill(&mut buf).map_err(|_| BlsError::InternalError)?;
+
+        Ok(RemoteSignerToken(hex::encode(buf)))
+    }
+
+    /// return the token of the file at `path`
+    ///
+    /// Returns `BlsError::IoError(PermissionDenied)` if the file is readable or writable by
+    /// others than its owner on Unix, and `BlsError::InvalidData` if it does not hold the hex
+    /// encoding of `REMOTE_SIGNER_TOKEN_SIZE` bytes.
+    pub fn read_file<P: AsRef<Path>>(path: P) -> Result<Self, BlsError> {
+        let path = path.as_ref();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let metadata = fs::metadata(path).map_err(|err| BlsError::IoError(err.kind()))?;
+            if metadata.permissions().mode() & 0o077 != 0 {
+                return Err(BlsError::IoError(std::io::ErrorKind::PermissionDenied));
+            }
+        }
+        let contents = fs::read_to_string(path).map_err(|err| BlsError::IoError(err.kind()))?;
+        let token = contents.trim_end();
+        if token.len() != 2 * REMOTE_SIGNER_TOKEN_SIZE || decode_hex(token).is_err() {
+            return Err(BlsError::InvalidData);
+        }
+
+        Ok(RemoteSignerToken(token.to_string()))
+    }
+
+    /// write `self` to the file at `path`, readable and writable by its owner only on Unix
+    pub fn write_file<P: AsRef<Path>>(&self, path: P) -> Result<(), BlsError> {
+        write_private_file_atomic(path.as_ref(), format!("{}\n", self.0).as_bytes())
+    }
+
+    /// return true if `token` is the one of `self`, in a time independent of the matching bytes
+    fn matches(&self, token: &str) -> bool {
+        token.len() == self.0.len()
+            && token
+                .bytes()
+                .zip(self.0.bytes())
+                .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+                == 0
+    }
+}
+
+impl PartialEq for RemoteSignerToken {
+    fn eq(&self, other: &Self) -> bool {
+        self.matches(&other.0)
+    }
+}
+
+impl fmt::Debug for RemoteSignerToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RemoteSignerToken(..)")
+    }
+}
+
+/// server signing with the keys of a `KeyRing`
+#[derive(Debug)]
+pub struct RemoteSignerServer {
+    listener: TcpListener,
+    service: Arc<Service>,
+}
+
+#[derive(Debug)]
+struct Service {
+    key_ring: KeyRing,
+    token: RemoteSignerToken,
+    connections: AtomicUsize,
+}
+
+/// HTTP message read from a connection
+struct Message {
+    start_line: String,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+impl Message {
+    /// return the value of the header `name`, if sent once
+    fn header(&self, name: &str) -> Option<&str> {
+        let mut values = self
+            .headers
+            .iter()
+            .filter(|(header, _)| header.eq_ignore_ascii_case(name));
+        match (values.next(), values.next()) {
+            (Some((_, value)), None) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl RemoteSignerServer {
+    /// return a server listening on the loopback address `addr` and signing with the keys of
+    /// `key_ring` for the requests holding `token`
+    ///
+    /// Returns `BlsError::InvalidAddress` if `addr` resolves to a non-loopback address.
+    pub fn bind<A: ToSocketAddrs>(
+        addr: A,
+        key_ring: KeyRing,
+        token: RemoteSignerToken,
+    ) -> Result<Self, BlsError> {
+        let addrs = addr
+            .to_socket_addrs()
+            .map_err(|err| BlsError::IoError(err.kind()))?
+            .collect::<Vec<SocketAddr>>();
+        if addrs.is_empty() || addrs.iter().any(|addr| !addr.ip().is_loopback()) {
+            return Err(BlsError::InvalidAddress);
+        }
+
+        Self::bind_remote(&addrs[..], key_ring, token)
+    }
+
+    /// return a server listening on `addr`, which may be reachable from other hosts, and
+    /// signing with the keys of `key_ring` for the requests holding `token`
+    ///
+    /// The token is sent in clear text: `addr` should only be reachable from trusted hosts.
+    pub fn bind_remote<A: ToSocketAddrs>(
+        addr: A,
+        key_ring: KeyRing,
+        token: RemoteSignerToken,
+    ) -> Result<Self, BlsError> {
+        let listener = TcpListener::bind(addr).map_err(|err| BlsError::IoError(err.kind()))?;
+
+        Ok(RemoteSignerServer {
+            listener,
+            service: Arc::new(Service {
+                key_ring,
+                token,
+                connections: AtomicUsize::new(0),
+            }),
+        })
+    }
+
+    /// return the address `self` listens on
+    pub fn local_addr(&self) -> Result<SocketAddr, BlsError> {
+        self.listener
+            .local_addr()
+            .map_err(|err| BlsError::IoError(err.kind()))
+    }
+
+    /// serve the requests of the incoming connections, each on its own thread, until listening
+    /// fails
+    ///
+    /// A failing connection is closed without stopping the server, as are the connections
+    /// beyond `REMOTE_SIGNER_MAX_CONNECTIONS` and those not sending their request within 2 seconds.
+    pub fn serve(&self) -> Result<(), BlsError> {
+        loop {
+            let (stream, _) = self
+                .listener
+                .accept()
+                .map_err(|err| BlsError::IoError(err.kind()))?;
+            if self.service.connections.fetch_add(1, Ordering::SeqCst)
+                >= REMOTE_SIGNER_MAX_CONNECTIONS
+            {
+                self.service.connections.fetch_sub(1, Ordering::SeqCst);
+                continue;
+            }
+
+            let service = Arc::clone(&self.service);
+            let spawned = thread::Builder::new().spawn(move || {
+                let _ = service.handle_connection(stream);
+                service.connections.fetch_sub(1, Ordering::SeqCst);
+            });
+            if spawned.is_err() {
+                self.service.connections.fetch_sub(1, Ordering::SeqCst);
+            }
+        }
+    }
+}
+
+impl Service {
+    fn handle_connection(&self, mut stream: TcpStream) -> Result<(), BlsError> {
+        stream
+            .set_read_timeout(Some(SERVER_IO_TIMEOUT))
+            .and_then(|_| stream.set_write_timeout(Some(SERVER_IO_TIMEOUT)))
+            .map_err(|err| BlsError::IoError(err.kind()))?;
+
+        let local_addr = stream
+            .local_addr()
+            .map_err(|err| BlsError::IoError(err.kind()))?;
+        let (status, body) = match read_message(&mut stream) {
+            Ok(request) => match self.check_request(&request, local_addr) {
+                Ok(()) => self.handle_request(&request.start_line, &request.body),
+                Err(response) => response,
+            },
+            Err(_) => error_response(400, "malformed request"),
+        };
+        let response = format!(
+            "HTTP/1.1 {status} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            reason_phrase(status),
+            body.len()
+        );
+
+        stream
+            .write_all(response.as_bytes())
+            .map_err(|err| BlsError::IoError(err.kind()))
+    }
+
+    /// check the `Host`, `Authorization` and `Content-Type` headers of `request`, received on
+    /// `local_addr`
+    fn check_request(
+        &self,
+        request: &Message,
+        local_addr: SocketAddr,
+    ) -> Result<(), (u16, String)> {
+        if request.header("host") != Some(local_addr.to_string().as_str()) {
+            return Err(error_response(403, "unexpected host"));
+        }
+        let token = request
+            .header("authorization")
+            .and_then(|value| value.strip_prefix("Bearer "));
+        if !token.is_some_and(|token| self.token.matches(token)) {
+            return Err(error_response(401, "unauthorized"));
+        }
+        let media_type = request
+            .header("content-type")
+            .map(|value| value.split(';').next().unwrap_or_default().trim());
+        if request.start_line.starts_with("POST ")
+            && !media_type
+                .is_some_and(|media_type| media_type.eq_ignore_ascii_case("application/json"))
+        {
+            return Err(error_response(415, "unsupported media type"));
+        }
+
+        Ok(())
+    }
+
+    fn handle_request(&self, start_line: &str, body: &[u8]) -> (u16, String) {
+        let mut parts = start_line.split(' ');
+        match (parts.next(), parts.next()) {
+            (Some("GET"), Some("/keys")) => {
+                let public_keys = self
+                    .key_ring
+                    .public_keys()
+                    .iter()
+                    .map(|public_key| public_key.serialize().map(hex::encode))
+                    .collect::<Result<Vec<String>, BlsError>>();
+                match public_keys {
+                    Ok(public_keys) => json_response(&KeysResponse { public_keys }),
+                    Err(err) => error_response(500, &err.to_string()),
+                }
+            }
+            (Some("POST"), Some("/sign")) => match self.sign(body) {
+                Ok(response) => json_response(&response),
+                Err(err) => sign_error_response(err),
+            },
+            (Some("POST"), Some("/sign_batch")) => match self.sign_batch(body) {
+                Ok(response) => json_response(&response),
+                Err(err) => sign_error_response(err),
+            },
+            _ => error_response(404, "not found"),
+        }
+    }
+
+    fn sign(&self, body: &[u8]) -> Result<SignResponse, BlsError> {
+        let request: SignRequest =
+            serde_json::from_slice(body).map_err(|_| BlsError::InvalidData)?;
+        let public_key = G2::from_serialized(&decode_hex(&request.public_key)?)?;
+        let signature = self
+            .key_ring
+            .sign(&public_key, &decode_hex(&request.message)?)?;
+
+        Ok(SignResponse {
+            signature: hex::encode(signature.serialize()?),
+        })
+    }
+
+    fn sign_batch(&self, body: &[u8]) -> Result<SignBatchResponse, BlsError> {
+        let request: SignBatchRequest =
+            serde_json::from_slice(body).map_err(|_| BlsError::InvalidData)?;
+        let public_key = G2::from_serialized(&decode_hex(&request.public_key)?)?;
+        let signatures = request
+            .messages
+            .iter()
+            .map(|message| {
+                let signature = self.key_ring.sign(&public_key, &decode_hex(message)?)?;
+                Ok(hex::encode(signature.serialize()?))
+            })
+            .collect::<Result<Vec<String>, BlsError>>()?;
+
+        Ok(SignBatchResponse { signatures })
+    }
+}
+
+/// `Signer` of a key served by a `RemoteSignerServer`
+///
+/// The signatures returned by the server are verified before being returned.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemoteSigner {
+    addr: SocketAddr,
+    public_key: G2,
+    token: RemoteSignerToken,
+}
+
+impl RemoteSigner {
+    /// return the signer of the key of `public_key` served at `addr`, authenticated by `token`
+    pub fn new(addr: SocketAddr, public_key: G2, token: RemoteSignerToken) -> Self {
+        RemoteSigner {
+            addr,
+            public_key,
+            token,
+        }
+    }
+
+    /// return the public keys of the keys served at `addr`, authenticated by `token`
+    ///
+    /// Returns `BlsError::Unauthorized` if the server refuses `token`.
+    pub fn public_keys(addr: SocketAddr, token: &RemoteSignerToken) -> Result<Vec<G2>, BlsError> {
+        let response: KeysResponse = request(addr, token, "GET", "/keys", "")?;
+
+        response
+            .public_keys
+            .iter()
+            .map(|public_key| G2::from_serialized(&decode_hex(public_key)?))
+            .collect()
+    }
+
+    /// return the address of the server
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+}
+
+impl Signer for RemoteSigner {
+    fn public_key(&self) -> G2 {
+        self.public_key
+    }
+
+    /// return the signature of `msg` by the remote key
+    ///
+    /// Returns `BlsError::UnknownPublicKey` if the server does not hold the key,
+    /// `BlsError::Unauthorized` if it refuses the token, `BlsError::IoError` if it cannot be
+    /// reached and `BlsError::InvalidSignature` if the returned signature does not verify.
+    fn sign(&self, msg: &[u8]) -> Result<G1, BlsError> {
+        let body = serde_json::to_string(&SignRequest {
+            public_key: hex::encode(self.public_key.serialize()?),
+            message: hex::encode(msg),
+        })
+        .map_err(|_| BlsError::SerializeError)?;
+        let response: SignResponse = request(self.addr, &self.token, "POST", "/sign", &body)?;
+
+        self.verified_signature(&response.signature, msg)
+    }
+
+    /// return the signatures of `msgs` by the remote key, requested at once
+    fn sign_batch(&self, msgs: &[&[u8]]) -> Result<Vec<G1>, BlsError> {
+        let body = serde_json::to_string(&SignBatchRequest {
+            public_key: hex::encode(self.public_key.serialize()?),
+            messages: msgs.iter().map(hex::encode).collect(),
+        })
+        .map_err(|_| BlsError::SerializeError)?;
+        let response: SignBatchResponse =
+            request(self.addr, &self.token, "POST", "/sign_batch", &body)?;
+        if response.signatures.len() != msgs.len() {
+            return Err(BlsError::InvalidData);
+        }
+
+        response
+            .signatures
+            .iter()
+            .zip(msgs)
+            .map(|(signature, msg)| self.verified_signature(signature, msg))
+            .collect()
+    }
+}
+
+impl RemoteSigner {
+    fn verified_signature(&self, signature: &str, msg: &[u8]) -> Result<G1, BlsError> {
+        let signature = G1::from_serialized(&decode_hex(signature)?)?;
+        if !signature.verify(self.public_key, msg) {
+            return Err(BlsError::InvalidSignature);
+        }
+
+        Ok(signature)
+    }
+}
+
+/// send a request to the server at `addr` and return its decoded response
+fn request<T: DeserializeOwned>(
+    addr: SocketAddr,
+    token: &RemoteSignerToken,
+    method: &str,
+    path: &str,
+    body: &str,
+) -> Result<T, BlsError> {
+    let mut stream = TcpStream::connect_timeout(&addr, IO_TIMEOUT)
+        .map_err(|err| BlsError::IoError(err.kind()))?;
+    stream
+        .set_read_timeout(Some(IO_TIMEOUT))
+        .and_then(|_| stream.set_write_timeout(Some(IO_TIMEOUT)))
+        .map_err(|err| BlsError::IoError(err.kind()))?;
+
+    let request = format!(
+        "{method} {path} HTTP/1.1\r\nHost: {addr}\r\nAuthorization: Bearer {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        token.0,
+        body.len()
+    );
+    stream
+        .write_all(request.as_bytes())
+        .map_err(|err| BlsError::IoError(err.kind()))?;
+
+    let response = read_message(&mut stream)?;
+    let status = response
+        .start_line
+        .split(' ')
+        .nth(1)
+        .and_then(|status| status.parse::<u16>().ok())
+        .ok_or(BlsError::InvalidData)?;
+    match status {
+        200 => serde_json::from_slice(&response.body).map_err(|_| BlsError::InvalidData),
+        401 | 403 => Err(BlsError::Unauthorized),
+        404 if path.starts_with("/sign") => Err(BlsError::UnknownPublicKey),
+        400..=499 => Err(BlsError::InvalidData),
+        _ => Err(BlsError::InternalError),
+    }
+}
+
+/// return the HTTP message read from `stream`
+fn read_message(stream: &mut TcpStream) -> Result<Message, BlsError> {
+    let mut reader = BufReader::new(stream.take(REMOTE_SIGNER_MAX_BODY_SIZE as u64 + 8192));
+    let start_line = read_line(&mut reader)?;
+
+    let mut headers = Vec::new();
+    loop {
+        let line = read_line(&mut reader)?;
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.push((name.trim().to_string(), value.trim().to_string()));
+        }
+    }
+    let mut message = Message {
+        start_line,
+        headers,
+        body: Vec::new(),
+    };
+    let content_length: usize = match message.header("content-length") {
+        Some(value) => value.parse().map_err(|_| BlsError::InvalidData)?,
+        None => 0,
+    };
+    if content_length > REMOTE_SIGNER_MAX_BODY_SIZE {
+        return Err(BlsError::BadSize);
+    }
+
+    message.body = vec![0u8; content_length];
+    reader
+        .read_exact(&mut message.body)
+        .map_err(|err| BlsError::IoError(err.kind()))?;
+
+    Ok(message)
+}
+
+fn read_line<R: BufRead>(reader: &mut R) -> Result<String, BlsError> {
+    let mut line = String::new();
+    let n = reader
+        .read_line(&mut line)
+        .map_err(|err| BlsError::IoError(err.kind()))?;
+    if n == 0 || !line.ends_with("\r\n") {
+        return Err(BlsError::InvalidData);
+    }
+    line.truncate(line.len() - 2);
+
+    Ok(line)
+}
+
+fn json_response<T: Serialize>(response: &T) -> (u16, String) {
+    match serde_json::to_string(response) {
+        Ok(body) => (200, body),
+        Err(_) => error_response(500, "serialize error"),
+    }
+}
+
+fn sign_error_response(err: BlsError) -> (u16, String) {
+    match err {
+        BlsError::UnknownPublicKey => error_response(404, "unknown public key"),
+        BlsError::SignatureCheckFailed => error_response(500, &err.to_string()),
+        err => error_response(400, &err.to_string()),
+    }
+}
+
+fn error_response(status: u16, error: &str) -> (u16, String) {
+    let body = serde_json::to_string(&ErrorResponse {
+        error: error.to_string(),
+    })
+    .unwrap_or_default();
+
+    (status, body)
+}
+
+fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        415 => "Unsupported Media Type",
+        _ => "Internal Server Error",
+    }
+}
+
+fn decode_hex(s: &str) -> Result<Vec<u8>, BlsError> {
+    hex::decode(s).map_err(|_| BlsError::InvalidData)
+}
@@ -65,6 +65,20 @@ impl SecretKey {
         v
     }
 
+    /// return the signature of `msg`, verified against `public_key` before it is returned
+    /// * `public_key` - public key of `self` computed beforehand, e.g. when the key was loaded
+    ///
+    /// Returns `BlsError::SignatureCheckFailed` if the signature does not verify, e.g. because
+    /// the memory of `self` was corrupted since `public_key` was computed.
+    pub fn sign_checked(&self, public_key: &G2, msg: &[u8]) -> Result<G1, BlsError> {
+        let signature = self.sign(msg);
+        if !signature.verify(*public_key, msg) {
+            return Err(BlsError::SignatureCheckFailed);
+        }
+
+        Ok(signature)
+    }
+
     /// return true if `buf` is deserialized successfully
     /// * `buf` - serialized data by `serialize`
     pub fn deserialize(&mut self, buf: &[u8]) -> bool {
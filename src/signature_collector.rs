@@ -0,0 +1,246 @@
+use std::collections::BTreeMap;
+
+use crate::g1::G1;
+use crate::g2::G2;
+use crate::invalid_shares::find_invalid_shares;
+use crate::signer::Signer;
+use crate::validator_set::ValidatorSet;
+use crate::BlsError;
+
+/// when the shares of a `SignatureCollector` are verified
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ShareVerification {
+    /// each share is verified when it is added
+    #[default]
+    Eager,
+    /// the shares are verified together, when they are aggregated
+    Lazy,
+}
+
+/// collector of the signature shares of a message by the validators of a `ValidatorSet`
+///
+/// Each validator has a weight, 1 by default, and the aggregated signature is only emitted once
+/// the signers reach the threshold weight, `2/3 + 1` of the total weight by default.
+#[derive(Debug, Clone)]
+pub struct SignatureCollector {
+    validator_set: ValidatorSet,
+    msg: Vec<u8>,
+    weights: Vec<u64>,
+    total_weight: u64,
+    threshold: Option<u64>,
+    verification: ShareVerification,
+    shares: BTreeMap<usize, G1>,
+    signed_weight: u64,
+}
+
+impl SignatureCollector {
+    /// return an empty collector of the shares of `msg` by the validators of `validator_set`
+    pub fn new(validator_set: ValidatorSet, msg: &[u8]) -> Self {
+        let weights = vec![1; validator_set.len()];
+        SignatureCollector {
+            total_weight: weights.len() as u64,
+            validator_set,
+            msg: msg.to_vec(),
+            weights,
+            threshold: None,
+            verification: ShareVerification::default(),
+            shares: BTreeMap::new(),
+            signed_weight: 0,
+        }
+    }
+
+    /// return `self` with the validator weights `weights`, in consensus order
+    ///
+    /// Returns `BlsError::BadSize` if there is not one weight per validator, and
+    /// `BlsError::InvalidData` if the total weight is zero, overflows or is below the threshold
+    /// weight set by `with_threshold`.
+    pub fn with_weights(mut self, weights: Vec<u64>) -> Result<Self, BlsError> {
+        if weights.len() != self.validator_set.len() {
+            return Err(BlsError::BadSize);
+        }
+        let total_weight = weights
+            .iter()
+            .try_fold(0u64, |total, weight| total.checked_add(*weight))
+            .filter(|total| *total > 0 && self.threshold.is_none_or(|t| t <= *total))
+            .ok_or(BlsError::InvalidData)?;
+
+        self.signed_weight = self.shares.keys().map(|index| weights[*index]).sum();
+        self.weights = weights;
+        self.total_weight = total_weight;
+
+        Ok(self)
+    }
+
+    /// return `self` with the threshold weight `threshold`
+    ///
+    /// Returns `BlsError::InvalidData` if `threshold` is zero or above the total weight.
+    pub fn with_threshold(mut self, threshold: u64) -> Result<Self, BlsError> {
+        if threshold == 0 || threshold > self.total_weight {
+            return Err(BlsError::InvalidData);
+        }
+
+        self.threshold = Some(threshold);
+        Ok(self)
+    }
+
+    /// return `self` with the share verification `verification`
+    pub fn with_verification(mut self, verification: ShareVerification) -> Self {
+        self.verification = verification;
+        self
+    }
+
+    /// return the validator set of `self`
+    pub fn validator_set(&self) -> &ValidatorSet {
+        &self.validator_set
+    }
+
+    /// return the message signed by the shares
+    pub fn msg(&self) -> &[u8] {
+        &self.msg
+    }
+
+    /// return the weight the signers must reach
+    pub fn threshold(&self) -> u64 {
+        self.threshold
+            .unwrap_or((self.total_weight as u128 * 2 / 3 + 1) as u64)
+    }
+
+    /// return the total weight of the validators
+    pub fn total_weight(&self) -> u64 {
+        self.total_weight
+    }
+
+    /// return the total weight of the signers
+    pub fn signed_weight(&self) -> u64 {
+        self.signed_weight
+    }
+
+    /// return true if the signers reached the threshold weight
+    pub fn is_threshold_reached(&self) -> bool {
+        self.signed_weight >= self.threshold()
+    }
+
+    /// return the indices of the signers, in increasing order
+    pub fn signers(&self) -> Vec<usize> {
+        self.shares.keys().copied().collect()
+    }
+
+    /// return the bitmap of the signers
+    pub fn bitmap(&self) -> Result<Vec<u8>, BlsError> {
+        self.validator_set.bitmap(&self.signers())
+    }
+
+    /// add the share `share` of the validator at `index`
+    ///
+    /// Returns `BlsError::UnknownSigner` if there is no validator at `index`,
+    /// `BlsError::DuplicateSigner` if it already signed, `BlsError::InvalidData` if `share` is
+    /// not a valid point and, with eager verification, `BlsError::InvalidSignature` if `share`
+    /// does not verify.
+    pub fn add(&mut self, index: usize, share: G1) -> Result<(), BlsError> {
+        let public_key = *self
+            .validator_set
+            .public_keys()
+            .get(index)
+            .ok_or(BlsError::UnknownSigner)?;
+        if self.shares.contains_key(&index) {
+            return Err(BlsError::DuplicateSigner);
+        }
+        if share.is_zero() || !share.is_valid_order() {
+            return Err(BlsError::InvalidData);
+        }
+        if self.verification == ShareVerification::Eager && !share.verify(public_key, &self.msg) {
+            return Err(BlsError::InvalidSignature);
+        }
+
+        self.shares.insert(index, share);
+        self.signed_weight += self.weights[index];
+
+        Ok(())
+    }
+
+    /// add the share signed by `signer`, the validator at `index`
+    ///
+    /// Returns `BlsError::PublicKeyMismatch` if `signer` does not hold the key of the validator,
+    /// and the errors of `add` otherwise.
+    pub fn add_signed<S: Signer + ?Sized>(
+        &mut self,
+        index: usize,
+        signer: &S,
+    ) -> Result<(), BlsError> {
+        let public_key = self
+            .validator_set
+            .public_keys()
+            .get(index)
+            .ok_or(BlsError::UnknownSigner)?;
+        if signer.public_key() != *public_key {
+            return Err(BlsError::PublicKeyMismatch);
+        }
+
+        let share = signer.sign(&self.msg)?;
+        self.add(index, share)
+    }
+
+    /// return the indices of the signers whose share does not verify, in increasing order
+    ///
+    /// The shares are bisected with aggregated checks, see `find_invalid_shares`.
+    pub fn invalid_shares(&self) -> Result<Vec<usize>, BlsError> {
+        let signers = self.signers();
+        let public_keys: Vec<G2> = signers
+            .iter()
+            .map(|index| self.validator_set.public_keys()[*index])
+            .collect();
+        let shares: Vec<G1> = self.shares.values().copied().collect();
+
+        Ok(find_invalid_shares(&public_keys, &shares, &self.msg)?
+            .into_iter()
+            .map(|position| signers[position])
+            .collect())
+    }
+
+    /// remove the shares which do not verify, and return the indices of their signers
+    pub fn remove_invalid_shares(&mut self) -> Result<Vec<usize>, BlsError> {
+        let invalid = self.invalid_shares()?;
+        for index in &invalid {
+            self.shares.remove(index);
+            self.signed_weight -= self.weights[*index];
+        }
+
+        Ok(invalid)
+    }
+
+    /// return the aggregated signature of the signers and their bitmap
+    ///
+    /// With lazy verification, the aggregated signature is verified first, and if it does not
+    /// verify the invalid shares are removed. Returns `BlsError::ThresholdNotReached` if the
+    /// signers with a valid share do not reach the threshold weight.
+    pub fn aggregate(&mut self) -> Result<(G1, Vec<u8>), BlsError> {
+        if !self.is_threshold_reached() {
+            return Err(BlsError::ThresholdNotReached);
+        }
+
+        let (mut signature, mut bitmap) = self.aggregate_shares()?;
+        if self.verification == ShareVerification::Lazy
+            && self
+                .validator_set
+                .verify_aggregated(&bitmap, &signature, &self.msg)
+                .is_err()
+        {
+            self.remove_invalid_shares()?;
+            if !self.is_threshold_reached() {
+                return Err(BlsError::ThresholdNotReached);
+            }
+            (signature, bitmap) = self.aggregate_shares()?;
+        }
+
+        Ok((signature, bitmap))
+    }
+
+    fn aggregate_shares(&self) -> Result<(G1, Vec<u8>), BlsError> {
+        let mut signature = G1::default();
+        for share in self.shares.values() {
+            signature.add_assign(*share);
+        }
+
+        Ok((signature, self.bitmap()?))
+    }
+}
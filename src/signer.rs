@@ -0,0 +1,34 @@
+use crate::g1::G1;
+use crate::g2::G2;
+use crate::secret_key::SecretKey;
+use crate::BlsError;
+
+/// holder of a secret key, signing as `SecretKey::sign`
+pub trait Signer {
+    /// return the public key of the held secret key
+    fn public_key(&self) -> G2;
+
+    /// return the signature of `msg` by the held secret key
+    fn sign(&self, msg: &[u8]) -> Result<G1, BlsError>;
+
+    /// return the signatures of `msgs` by the held secret key, in order
+    fn sign_batch(&self, msgs: &[&[u8]]) -> Result<Vec<G1>, BlsError> {
+        msgs.iter().map(|msg| self.sign(msg)).collect()
+    }
+}
+
+impl Signer for SecretKey {
+    fn public_key(&self) -> G2 {
+        self.get_public_key()
+    }
+
+    /// return the signature of `msg` by `self`, or `BlsError::InvalidData` for a zero or
+    /// invalid key
+    fn sign(&self, msg: &[u8]) -> Result<G1, BlsError> {
+        if self.is_zero() || !self.is_valid() {
+            return Err(BlsError::InvalidData);
+        }
+
+        Ok(SecretKey::sign(self, msg))
+    }
+}
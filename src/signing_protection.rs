@@ -0,0 +1,311 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::atomic_file::write_file_atomic;
+use crate::equivocation::Slot;
+use crate::g1::G1;
+use crate::g2::G2;
+use crate::signer::Signer;
+use crate::BlsError;
+
+/// payload hashes of the signatures, by serialized public key and slot
+type Records = BTreeMap<(Vec<u8>, Slot), Vec<u8>>;
+
+/// version of the signing protection interchange format
+pub const SIGNING_PROTECTION_VERSION: u32 = 1;
+
+/// signature recorded by a `SigningProtection`, as exchanged in its interchange format
+///
+/// `public_key` is the hex serialized `G2` key and `payload_hash` the hex SHA-256 digest of
+/// the signed payload.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignedSlot {
+    pub public_key: String,
+    pub epoch: u32,
+    pub round: u64,
+    pub kind: u8,
+    pub payload_hash: String,
+}
+
+/// epoch and round below which a `SigningProtection` holds no record and refuses to sign
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Watermark {
+    pub epoch: u32,
+    pub round: u64,
+}
+
+impl Watermark {
+    /// return true if `slot` is below `self`
+    fn is_above(&self, slot: &Slot) -> bool {
+        (slot.epoch, slot.round) < (self.epoch, self.round)
+    }
+}
+
+/// interchange format of the records of a `SigningProtection`
+///
+/// Slots below `watermark` are not recorded and must not be signed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SigningProtectionInterchange {
+    pub version: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub watermark: Option<Watermark>,
+    pub records: Vec<SignedSlot>,
+}
+
+/// record of the payloads signed for each key and slot, refusing to sign conflicting payloads
+///
+/// When backed by a file, each new record is written to the file before the signature is
+/// returned, so that a crash never forgets a released signature. As the whole file is written
+/// for each record, the records of past slots should be dropped regularly with `prune`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SigningProtection {
+    path: Option<PathBuf>,
+    watermark: Option<Watermark>,
+    records: Records,
+}
+
+impl SigningProtection {
+    /// return an empty signing protection, kept in memory only
+    pub fn new() -> Self {
+        SigningProtection::default()
+    }
+
+    /// return the signing protection backed by the file at `path`, empty if the file does not
+    /// exist yet
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, BlsError> {
+        let mut protection = SigningProtection {
+            path: Some(path.as_ref().to_path_buf()),
+            watermark: None,
+            records: BTreeMap::new(),
+        };
+
+        match fs::read_to_string(path) {
+            Ok(json) => (protection.watermark, protection.records) = decode_records(&json)?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => (),
+            Err(err) => return Err(BlsError::IoError(err.kind())),
+        }
+
+        Ok(protection)
+    }
+
+    /// return the number of records of `self`
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// return true if `self` holds no record
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// return the watermark below which `self` refuses to sign, once pruned
+    pub fn watermark(&self) -> Option<Watermark> {
+        self.watermark
+    }
+
+    /// check that `public_key` may sign `payload` for `slot`
+    ///
+    /// Returns `BlsError::DoubleSigning` if it already signed another payload for `slot`, or if
+    /// `slot` is below the watermark, whose records were dropped.
+    pub fn check(&self, public_key: &G2, slot: Slot, payload: &[u8]) -> Result<(), BlsError> {
+        if self
+            .watermark
+            .is_some_and(|watermark| watermark.is_above(&slot))
+        {
+            return Err(BlsError::DoubleSigning);
+        }
+
+        match self.records.get(&(public_key.serialize()?, slot)) {
+            Some(payload_hash) if *payload_hash != hash_payload(payload) => {
+                Err(BlsError::DoubleSigning)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// return the signature of `payload` by `signer` for `slot`, once recorded
+    ///
+    /// Signing the same payload again for the same slot returns the same signature. Returns
+    /// `BlsError::DoubleSigning` if `signer` already signed another payload for `slot` or if
+    /// `slot` is below the watermark.
+    pub fn sign<S: Signer + ?Sized>(
+        &mut self,
+        signer: &S,
+        slot: Slot,
+        payload: &[u8],
+    ) -> Result<G1, BlsError> {
+        let public_key = signer.public_key();
+        self.check(&public_key, slot, payload)?;
+
+        let key = (public_key.serialize()?, slot);
+        if !self.records.contains_key(&key) {
+            self.records.insert(key.clone(), hash_payload(payload));
+            if let Err(err) = self.save() {
+                self.records.remove(&key);
+                return Err(err);
+            }
+        }
+
+        signer.sign(payload)
+    }
+
+    /// drop the records of the slots below `watermark` and refuse to sign for these slots
+    ///
+    /// The watermark never decreases: a lower `watermark` than the current one is ignored.
+    pub fn prune(&mut self, watermark: Watermark) -> Result<(), BlsError> {
+        let watermark = self
+            .watermark
+            .map_or(watermark, |current| current.max(watermark));
+        let records = pruned(&self.records, watermark);
+
+        let previous = (
+            self.watermark.replace(watermark),
+            std::mem::replace(&mut self.records, records),
+        );
+        if let Err(err) = self.save() {
+            (self.watermark, self.records) = previous;
+            return Err(err);
+        }
+
+        Ok(())
+    }
+
+    /// return the records of `self` in the interchange format
+    pub fn export(&self) -> Result<String, BlsError> {
+        serde_json::to_string_pretty(&self.interchange(self.watermark, &self.records))
+            .map_err(|_| BlsError::SerializeError)
+    }
+
+    /// return the records of `self` of the slots from `since` on in the interchange format
+    ///
+    /// The exported watermark is raised to `since`, so that the importer refuses to sign for
+    /// the slots whose records are left out.
+    pub fn export_since(&self, since: Watermark) -> Result<String, BlsError> {
+        let watermark = self.watermark.map_or(since, |current| current.max(since));
+        let records = pruned(&self.records, watermark);
+
+        serde_json::to_string_pretty(&self.interchange(Some(watermark), &records))
+            .map_err(|_| BlsError::SerializeError)
+    }
+
+    /// add the records of the interchange format `json` to `self`
+    ///
+    /// The watermark of `self` is raised to the one of `json`, dropping the records below it.
+    /// Returns `BlsError::DoubleSigning` without importing anything if a record conflicts with
+    /// another record.
+    pub fn import(&mut self, json: &str) -> Result<(), BlsError> {
+        let (watermark, imported) = decode_records(json)?;
+        let mut records = self.records.clone();
+        for (key, payload_hash) in imported {
+            match records.get(&key) {
+                Some(recorded) if *recorded != payload_hash => return Err(BlsError::DoubleSigning),
+                Some(_) => (),
+                None => {
+                    records.insert(key, payload_hash);
+                }
+            }
+        }
+
+        let watermark = match (self.watermark, watermark) {
+            (Some(current), Some(imported)) => Some(current.max(imported)),
+            (current, imported) => current.or(imported),
+        };
+        if let Some(watermark) = watermark {
+            records = pruned(&records, watermark);
+        }
+
+        let previous = (
+            std::mem::replace(&mut self.watermark, watermark),
+            std::mem::replace(&mut self.records, records),
+        );
+        if let Err(err) = self.save() {
+            (self.watermark, self.records) = previous;
+            return Err(err);
+        }
+
+        Ok(())
+    }
+
+    fn interchange(
+        &self,
+        watermark: Option<Watermark>,
+        records: &Records,
+    ) -> SigningProtectionInterchange {
+        SigningProtectionInterchange {
+            version: SIGNING_PROTECTION_VERSION,
+            watermark,
+            records: records
+                .iter()
+                .map(|((public_key, slot), payload_hash)| SignedSlot {
+                    public_key: hex::encode(public_key),
+                    epoch: slot.epoch,
+                    round: slot.round,
+                    kind: slot.kind,
+                    payload_hash: hex::encode(payload_hash),
+                })
+                .collect(),
+        }
+    }
+
+    fn save(&self) -> Result<(), BlsError> {
+        match &self.path {
+            Some(path) => write_file_atomic(path, self.export()?.as_bytes()),
+            None => Ok(()),
+        }
+    }
+}
+
+/// return the records of `records` of the slots from `watermark` on
+fn pruned(records: &Records, watermark: Watermark) -> Records {
+    records
+        .iter()
+        .filter(|((_, slot), _)| !watermark.is_above(slot))
+        .map(|(key, payload_hash)| (key.clone(), payload_hash.clone()))
+        .collect()
+}
+
+/// return the watermark and the records of the interchange format `json`
+///
+/// Returns `BlsError::DoubleSigning` if two records conflict.
+fn decode_records(json: &str) -> Result<(Option<Watermark>, Records), BlsError> {
+    let interchange: SigningProtectionInterchange =
+        serde_json::from_str(json).map_err(|_| BlsError::InvalidData)?;
+    if interchange.version != SIGNING_PROTECTION_VERSION {
+        return Err(BlsError::InvalidData);
+    }
+
+    let mut records = BTreeMap::new();
+    for record in interchange.records {
+        let public_key = G2::from_serialized(&decode_hex(&record.public_key)?)?.serialize()?;
+        let payload_hash = decode_hex(&record.payload_hash)?;
+        if payload_hash.len() != Sha256::output_size() {
+            return Err(BlsError::InvalidData);
+        }
+
+        let slot = Slot {
+            epoch: record.epoch,
+            round: record.round,
+            kind: record.kind,
+        };
+        match records.insert((public_key, slot), payload_hash.clone()) {
+            Some(recorded) if recorded != payload_hash => return Err(BlsError::DoubleSigning),
+            _ => (),
+        }
+    }
+
+    Ok((interchange.watermark, records))
+}
+
+fn hash_payload(payload: &[u8]) -> Vec<u8> {
+    Sha256::digest(payload).to_vec()
+}
+
+fn decode_hex(s: &str) -> Result<Vec<u8>, BlsError> {
+    hex::decode(s).map_err(|_| BlsError::InvalidData)
+}
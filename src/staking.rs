@@ -0,0 +1,65 @@
+use bech32::primitives::decode::CheckedHrpstring;
+use bech32::{Bech32, Hrp};
+
+use crate::g1::G1;
+use crate::g2::G2;
+use crate::secret_key::SecretKey;
+use crate::BlsError;
+
+/// human-readable part of MultiversX bech32 addresses
+pub const ADDRESS_HRP: &str = "erd";
+/// size in bytes of MultiversX addresses
+pub const ADDRESS_SIZE: usize = 32;
+
+/// return the 32-byte address of the bech32 address `address`
+pub fn decode_address(address: &str) -> Result<Vec<u8>, BlsError> {
+    let checked = CheckedHrpstring::new::<Bech32>(address).map_err(|_| BlsError::InvalidAddress)?;
+    if checked.hrp().as_str() != ADDRESS_HRP {
+        return Err(BlsError::InvalidAddress);
+    }
+
+    let buf: Vec<u8> = checked.byte_iter().collect();
+    if buf.len() != ADDRESS_SIZE {
+        return Err(BlsError::InvalidAddress);
+    }
+
+    Ok(buf)
+}
+
+/// return the bech32 address of the 32-byte address `buf`
+pub fn encode_address(buf: &[u8]) -> Result<String, BlsError> {
+    if buf.len() != ADDRESS_SIZE {
+        return Err(BlsError::InvalidAddress);
+    }
+
+    bech32::encode::<Bech32>(Hrp::parse_unchecked(ADDRESS_HRP), buf)
+        .map_err(|_| BlsError::SerializeError)
+}
+
+impl SecretKey {
+    /// return the proof of ownership of `self` by the owner of `address`, as expected by the
+    /// staking contract
+    ///
+    /// The proof is the signature of the 32-byte address decoded from the bech32 `address`.
+    pub fn sign_staking_owner(&self, address: &str) -> Result<G1, BlsError> {
+        Ok(self.sign(&decode_address(address)?))
+    }
+}
+
+impl G2 {
+    /// check the proof of ownership `signature` of `self` by the owner of `address`
+    pub fn verify_staking_owner(&self, address: &str, signature: &G1) -> Result<(), BlsError> {
+        let address = decode_address(address)?;
+        if self.is_zero() || !self.is_valid_order() {
+            return Err(BlsError::InvalidData);
+        }
+        if signature.is_zero() || !signature.is_valid_order() {
+            return Err(BlsError::InvalidData);
+        }
+        if !signature.verify(*self, &address) {
+            return Err(BlsError::InvalidSignature);
+        }
+
+        Ok(())
+    }
+}
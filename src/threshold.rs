@@ -0,0 +1,150 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::bls_api::{blsSecretKeyShare, blsSignatureRecover};
+use crate::g1::G1;
+use crate::g2::G2;
+use crate::init::{init_library, INIT};
+use crate::secret_key::SecretKey;
+use crate::signer::Signer;
+use crate::BlsError;
+
+/// share of a secret key split with `split_secret_key`
+///
+/// The signatures of `threshold` shares of a message recover, with `recover_signature`, the
+/// signature of the message by the split key.
+#[derive(Clone, PartialEq)]
+pub struct ThresholdShare {
+    id: u64,
+    secret_key: SecretKey,
+}
+
+impl fmt::Debug for ThresholdShare {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ThresholdShare")
+            .field("id", &self.id)
+            .field("public_key", &self.secret_key.get_public_key())
+            .finish()
+    }
+}
+
+impl ThresholdShare {
+    /// return the share `secret_key` of id `id`
+    pub fn new(id: u64, secret_key: SecretKey) -> Self {
+        ThresholdShare { id, secret_key }
+    }
+
+    /// return the id of `self`
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// return the secret key of `self`
+    pub fn secret_key(&self) -> &SecretKey {
+        &self.secret_key
+    }
+}
+
+impl Signer for ThresholdShare {
+    fn public_key(&self) -> G2 {
+        self.secret_key.get_public_key()
+    }
+
+    /// return the signature share of `msg` by `self`
+    fn sign(&self, msg: &[u8]) -> Result<G1, BlsError> {
+        Signer::sign(&self.secret_key, msg)
+    }
+}
+
+/// return the shares of ids `ids` of `secret_key`, any `threshold` of which recover its
+/// signatures
+///
+/// Returns `BlsError::InvalidData` if `threshold` is zero or exceeds the number of ids, or if
+/// an id is zero or repeated.
+pub fn split_secret_key(
+    secret_key: &SecretKey,
+    threshold: usize,
+    ids: &[u64],
+) -> Result<Vec<ThresholdShare>, BlsError> {
+    if secret_key.is_zero() || !secret_key.is_valid() {
+        return Err(BlsError::InvalidData);
+    }
+    if threshold == 0 || threshold > ids.len() {
+        return Err(BlsError::InvalidData);
+    }
+    let ids = share_ids(ids)?;
+
+    // coefficients of a random polynomial of degree `threshold - 1` whose value at 0 is the key
+    let mut coefficients = vec![*secret_key];
+    for _ in 1..threshold {
+        let mut coefficient = SecretKey::default();
+        coefficient.set_by_csprng();
+        coefficients.push(coefficient);
+    }
+
+    INIT.call_once(init_library);
+    ids.into_iter()
+        .map(|(id, fr_id)| {
+            let mut share = SecretKey::default();
+            let ret = unsafe {
+                blsSecretKeyShare(
+                    &mut share,
+                    coefficients.as_ptr(),
+                    coefficients.len(),
+                    &fr_id,
+                )
+            };
+            if ret != 0 {
+                return Err(BlsError::InternalError);
+            }
+
+            Ok(ThresholdShare::new(id, share))
+        })
+        .collect()
+}
+
+/// return the signature recovered from the signature shares `signatures`, by share id
+///
+/// The signature is the one of the split key only if at least `threshold` valid shares of the
+/// same message are given. Returns `BlsError::InvalidData` if `signatures` is empty or if an
+/// id is zero or repeated.
+pub fn recover_signature(signatures: &[(u64, G1)]) -> Result<G1, BlsError> {
+    if signatures.is_empty() {
+        return Err(BlsError::InvalidData);
+    }
+    let ids: Vec<u64> = signatures.iter().map(|(id, _)| *id).collect();
+    let fr_ids: Vec<SecretKey> = share_ids(&ids)?.into_iter().map(|(_, id)| id).collect();
+    let shares: Vec<G1> = signatures.iter().map(|(_, signature)| *signature).collect();
+
+    INIT.call_once(init_library);
+    let mut signature = G1::default();
+    let ret = unsafe {
+        blsSignatureRecover(
+            &mut signature,
+            shares.as_ptr(),
+            fr_ids.as_ptr(),
+            shares.len(),
+        )
+    };
+    if ret != 0 {
+        return Err(BlsError::InternalError);
+    }
+
+    Ok(signature)
+}
+
+/// return `ids` with their scalar, the little-endian id
+fn share_ids(ids: &[u64]) -> Result<Vec<(u64, SecretKey)>, BlsError> {
+    let mut seen = HashSet::new();
+    ids.iter()
+        .map(|&id| {
+            if id == 0 || !seen.insert(id) {
+                return Err(BlsError::InvalidData);
+            }
+            let mut buf = [0u8; 32];
+            buf[..8].copy_from_slice(&id.to_le_bytes());
+
+            Ok((id, SecretKey::from_serialized(&buf)?))
+        })
+        .collect()
+}
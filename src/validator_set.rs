@@ -0,0 +1,196 @@
+use std::collections::HashSet;
+
+use crate::g1::G1;
+use crate::g2::G2;
+use crate::BlsError;
+
+/// ordered public keys of a consensus group, selected by MultiversX consensus bitmaps
+///
+/// Validator `i` is selected when bit `i % 8` (least significant first) of byte `i / 8` of the
+/// bitmap is set. A bitmap must have exactly `bitmap_size()` bytes and no bit set past the last
+/// validator.
+///
+/// The sum of all the public keys is cached, so that the aggregated key of a bitmap selecting
+/// most validators is computed by subtracting the few non-signers from it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidatorSet {
+    public_keys: Vec<G2>,
+    total_public_key: G2,
+}
+
+impl ValidatorSet {
+    /// return the validator set of `public_keys`, in consensus order
+    pub fn new(public_keys: Vec<G2>) -> Result<Self, BlsError> {
+        if public_keys.is_empty() {
+            return Err(BlsError::InvalidData);
+        }
+
+        let mut seen = HashSet::new();
+        for public_key in &public_keys {
+            if public_key.is_zero() || !public_key.is_valid_order() || !public_key.is_valid() {
+                return Err(BlsError::InvalidData);
+            }
+            if !seen.insert(public_key.serialize()?) {
+                return Err(BlsError::DuplicatePublicKey);
+            }
+        }
+
+        let mut total_public_key = G2::default();
+        for public_key in &public_keys {
+            total_public_key.add_assign(*public_key);
+        }
+
+        Ok(ValidatorSet {
+            public_keys,
+            total_public_key,
+        })
+    }
+
+    /// return the public keys of `self`, in consensus order
+    pub fn public_keys(&self) -> &[G2] {
+        &self.public_keys
+    }
+
+    /// return the sum of all the public keys of `self`
+    pub fn total_public_key(&self) -> G2 {
+        self.total_public_key
+    }
+
+    /// return the number of validators of `self`
+    pub fn len(&self) -> usize {
+        self.public_keys.len()
+    }
+
+    /// return true if `self` holds no validator
+    pub fn is_empty(&self) -> bool {
+        self.public_keys.is_empty()
+    }
+
+    /// return the index of `public_key` in `self`
+    pub fn index_of(&self, public_key: &G2) -> Option<usize> {
+        self.public_keys.iter().position(|pk| pk == public_key)
+    }
+
+    /// return the size in bytes of the bitmaps of `self`
+    pub fn bitmap_size(&self) -> usize {
+        self.len().div_ceil(8)
+    }
+
+    /// return the bitmap selecting the validators at `indices`
+    pub fn bitmap(&self, indices: &[usize]) -> Result<Vec<u8>, BlsError> {
+        let mut bitmap = vec![0u8; self.bitmap_size()];
+        for &index in indices {
+            if index >= self.len() {
+                return Err(BlsError::InvalidBitmap);
+            }
+            bitmap[index / 8] |= 1 << (index % 8);
+        }
+
+        Ok(bitmap)
+    }
+
+    /// return the indices of the validators selected by `bitmap`, in increasing order
+    pub fn selected_indices(&self, bitmap: &[u8]) -> Result<Vec<usize>, BlsError> {
+        self.check_bitmap(bitmap)?;
+
+        Ok((0..self.len())
+            .filter(|&index| is_selected(bitmap, index))
+            .collect())
+    }
+
+    /// return the public keys of the validators selected by `bitmap`, in consensus order
+    pub fn selected_public_keys(&self, bitmap: &[u8]) -> Result<Vec<G2>, BlsError> {
+        Ok(self
+            .selected_indices(bitmap)?
+            .into_iter()
+            .map(|index| self.public_keys[index])
+            .collect())
+    }
+
+    /// return the sum of the public keys of the validators selected by `bitmap`
+    ///
+    /// The selected keys are summed when they are at most half of the validators, otherwise the
+    /// other keys are subtracted from `total_public_key`. Returns `BlsError::InvalidBitmap` if
+    /// `bitmap` selects no validator.
+    pub fn aggregate_public_key(&self, bitmap: &[u8]) -> Result<G2, BlsError> {
+        let signers = self.count_selected(bitmap)?;
+        if signers * 2 > self.len() {
+            self.aggregate_public_key_by_complement(bitmap)
+        } else {
+            self.aggregate_public_key_by_sum(bitmap)
+        }
+    }
+
+    /// return the sum of the public keys of the validators selected by `bitmap`, adding them
+    pub fn aggregate_public_key_by_sum(&self, bitmap: &[u8]) -> Result<G2, BlsError> {
+        if self.count_selected(bitmap)? == 0 {
+            return Err(BlsError::InvalidBitmap);
+        }
+
+        let mut aggregated = G2::default();
+        for (index, public_key) in self.public_keys.iter().enumerate() {
+            if is_selected(bitmap, index) {
+                aggregated.add_assign(*public_key);
+            }
+        }
+
+        Ok(aggregated)
+    }
+
+    /// return the sum of the public keys of the validators selected by `bitmap`, subtracting
+    /// the other ones from `total_public_key`
+    pub fn aggregate_public_key_by_complement(&self, bitmap: &[u8]) -> Result<G2, BlsError> {
+        if self.count_selected(bitmap)? == 0 {
+            return Err(BlsError::InvalidBitmap);
+        }
+
+        let mut aggregated = self.total_public_key;
+        for (index, public_key) in self.public_keys.iter().enumerate() {
+            if !is_selected(bitmap, index) {
+                aggregated.sub_assign(*public_key);
+            }
+        }
+
+        Ok(aggregated)
+    }
+
+    /// check the aggregated signature `signature` of `msg` by the validators selected by `bitmap`
+    pub fn verify_aggregated(
+        &self,
+        bitmap: &[u8],
+        signature: &G1,
+        msg: &[u8],
+    ) -> Result<(), BlsError> {
+        let public_key = self.aggregate_public_key(bitmap)?;
+        if signature.is_zero() || !signature.is_valid_order() {
+            return Err(BlsError::InvalidData);
+        }
+        if !signature.verify(public_key, msg) {
+            return Err(BlsError::InvalidSignature);
+        }
+
+        Ok(())
+    }
+
+    fn count_selected(&self, bitmap: &[u8]) -> Result<usize, BlsError> {
+        self.check_bitmap(bitmap)?;
+
+        Ok(bitmap.iter().map(|byte| byte.count_ones() as usize).sum())
+    }
+
+    fn check_bitmap(&self, bitmap: &[u8]) -> Result<(), BlsError> {
+        if bitmap.len() != self.bitmap_size() {
+            return Err(BlsError::InvalidBitmap);
+        }
+        let used_bits = self.len() % 8;
+        if used_bits != 0 && bitmap[bitmap.len() - 1] >> used_bits != 0 {
+            return Err(BlsError::InvalidBitmap);
+        }
+
+        Ok(())
+    }
+}
+
+fn is_selected(bitmap: &[u8], index: usize) -> bool {
+    bitmap[index / 8] & (1 << (index % 8)) != 0
+}
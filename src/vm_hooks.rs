@@ -0,0 +1,104 @@
+//! Semantics of the BLS hooks the MultiversX VM exposes to smart contracts.
+//!
+//! `verifyBLS`, `verifyBLSSignatureShare` and `verifyBLSAggregatedSignature` read the serialized
+//! `G2` public keys, the message and the serialized `G1` signature from managed buffers, and the
+//! list of keys of `verifyBLSAggregatedSignature` from a `ManagedVec<ManagedBuffer>`, whose data
+//! is the 4-byte big-endian handles of its items. A hook returns `HOOK_SUCCESS` when the
+//! signature verifies, and otherwise fails the execution with `BLS_VERIFY_ERROR` and returns
+//! `HOOK_FAILURE`.
+
+use crate::multisig::kosk;
+use crate::BlsError;
+
+/// size in bytes of the public keys read by the hooks
+pub const BLS_PUBLIC_KEY_LENGTH: usize = 96;
+/// size in bytes of the signatures read by the hooks
+pub const BLS_SIGNATURE_LENGTH: usize = 48;
+/// size in bytes of a managed buffer handle in the data of a `ManagedVec<ManagedBuffer>`
+pub const MANAGED_HANDLE_LENGTH: usize = 4;
+
+/// value returned by a hook whose signature verifies
+pub const HOOK_SUCCESS: i32 = 0;
+/// value returned by a hook whose signature does not verify
+pub const HOOK_FAILURE: i32 = -1;
+/// message of the execution failure of a hook whose signature does not verify
+pub const BLS_VERIFY_ERROR: &str = "bls verify error";
+
+/// check the signature `signature` of `message` by `key`, as `verifyBLS`
+///
+/// Returns `BlsError::InvalidData` if `message` is empty, or if `key` or `signature` is not a
+/// valid point of the expected length, and `BlsError::InvalidSignature` if `signature` does
+/// not verify.
+pub fn verify_bls(key: &[u8], message: &[u8], signature: &[u8]) -> Result<(), BlsError> {
+    check_lengths(key, signature)?;
+
+    kosk::verify_signature_share(key, message, signature)
+}
+
+/// check the signature share `signature` of `message` by `key`, as `verifyBLSSignatureShare`
+pub fn verify_bls_signature_share(
+    key: &[u8],
+    message: &[u8],
+    signature: &[u8],
+) -> Result<(), BlsError> {
+    if key.is_empty() || message.is_empty() || signature.is_empty() {
+        return Err(BlsError::InvalidData);
+    }
+
+    verify_bls(key, message, signature)
+}
+
+/// check the aggregated signature `signature` of `message` by all of `keys`, as
+/// `verifyBLSAggregatedSignature`
+///
+/// Returns `BlsError::InvalidData` if `keys` or `message` is empty, or if a key or
+/// `signature` is not a valid point of the expected length.
+pub fn verify_bls_aggregated_signature<K: AsRef<[u8]>>(
+    keys: &[K],
+    message: &[u8],
+    signature: &[u8],
+) -> Result<(), BlsError> {
+    for key in keys {
+        check_lengths(key.as_ref(), signature)?;
+    }
+
+    kosk::verify_aggregated_signature(keys, message, signature)
+}
+
+/// return the item handles of the data `data` of a `ManagedVec<ManagedBuffer>`
+///
+/// Returns `BlsError::BadSize` if `data` is not a sequence of 4-byte handles.
+pub fn decode_managed_vec_handles(data: &[u8]) -> Result<Vec<i32>, BlsError> {
+    if !data.len().is_multiple_of(MANAGED_HANDLE_LENGTH) {
+        return Err(BlsError::BadSize);
+    }
+
+    Ok(data
+        .chunks_exact(MANAGED_HANDLE_LENGTH)
+        .map(|chunk| i32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        .collect())
+}
+
+/// return the data of a `ManagedVec<ManagedBuffer>` holding the items of handles `handles`
+pub fn encode_managed_vec_handles(handles: &[i32]) -> Vec<u8> {
+    handles
+        .iter()
+        .flat_map(|handle| handle.to_be_bytes())
+        .collect()
+}
+
+/// return the value returned by a hook whose verification resulted in `result`
+pub fn return_code(result: &Result<(), BlsError>) -> i32 {
+    match result {
+        Ok(()) => HOOK_SUCCESS,
+        Err(_) => HOOK_FAILURE,
+    }
+}
+
+fn check_lengths(key: &[u8], signature: &[u8]) -> Result<(), BlsError> {
+    if key.len() != BLS_PUBLIC_KEY_LENGTH || signature.len() != BLS_SIGNATURE_LENGTH {
+        return Err(BlsError::InvalidData);
+    }
+
+    Ok(())
+}
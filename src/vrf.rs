@@ -0,0 +1,75 @@
+use sha2::{Digest, Sha256};
+
+use crate::bls_api::{blsVerifyPairing, mclBnG1_hashAndMapToWithDst};
+use crate::g1::G1;
+use crate::g2::G2;
+use crate::init::{init_library, INIT};
+use crate::secret_key::SecretKey;
+use crate::BlsError;
+
+/// domain separation tag hashing the VRF inputs to `G1`
+pub const VRF_DST: &str = "MULTIVERSX-BLS-VRF-V01-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+/// size in bytes of the VRF outputs
+pub const VRF_OUTPUT_SIZE: usize = 32;
+
+impl SecretKey {
+    /// return the VRF output of `input` and its proof
+    ///
+    /// The proof is the unique `G1` point `sk * H(input)`, `H` hashing to `G1` with `VRF_DST`
+    /// rather than the hash of ordinary signatures, and the output is the SHA-256 digest of the
+    /// serialized proof.
+    pub fn vrf_prove(&self, input: &[u8]) -> Result<([u8; VRF_OUTPUT_SIZE], G1), BlsError> {
+        if self.is_zero() || !self.is_valid() {
+            return Err(BlsError::InvalidData);
+        }
+
+        let mut proof = hash_to_g1(input)?;
+        proof.mul_assign(self);
+
+        Ok((vrf_output(&proof)?, proof))
+    }
+}
+
+impl G2 {
+    /// return the VRF output of `input` if `proof` is its proof by `self`
+    pub fn vrf_verify(&self, input: &[u8], proof: &G1) -> Option<[u8; VRF_OUTPUT_SIZE]> {
+        if self.is_zero() || !self.is_valid_order() {
+            return None;
+        }
+        if proof.is_zero() || !proof.is_valid_order() {
+            return None;
+        }
+
+        let hash = hash_to_g1(input).ok()?;
+        if unsafe { blsVerifyPairing(proof, &hash, self) } != 1 {
+            return None;
+        }
+
+        vrf_output(proof).ok()
+    }
+}
+
+fn hash_to_g1(input: &[u8]) -> Result<G1, BlsError> {
+    INIT.call_once(init_library);
+
+    let mut hash = G1::default();
+    let dst = VRF_DST.as_bytes();
+    let n = unsafe {
+        mclBnG1_hashAndMapToWithDst(
+            &mut hash,
+            input.as_ptr(),
+            input.len(),
+            dst.as_ptr(),
+            dst.len(),
+        )
+    };
+    if n != 0 || hash.is_zero() {
+        return Err(BlsError::InternalError);
+    }
+
+    Ok(hash)
+}
+
+fn vrf_output(proof: &G1) -> Result<[u8; VRF_OUTPUT_SIZE], BlsError> {
+    Ok(Sha256::digest(proof.serialize()?).into())
+}
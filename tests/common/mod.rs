@@ -0,0 +1,25 @@
+//! Helpers shared by the integration tests.
+
+#![allow(dead_code)]
+
+use multiversx_bls::SecretKey;
+
+/// serialized secret key of `tests/validatorKey.pem`
+pub const SECRET_KEY_HEX: &str = "939facbf61706bf5ef777f9c97e38353a251571682f201bb45bffeb50de84e17";
+
+/// r - 1, the scalar negating a point
+pub const MINUS_ONE_HEX: &str = "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000000";
+
+pub fn random_secret_key() -> SecretKey {
+    let mut sk = SecretKey::default();
+    sk.set_by_csprng();
+    sk
+}
+
+pub fn random_secret_keys(n: usize) -> Vec<SecretKey> {
+    (0..n).map(|_| random_secret_key()).collect()
+}
+
+pub fn fixture_secret_key() -> SecretKey {
+    SecretKey::from_serialized(&hex::decode(SECRET_KEY_HEX).unwrap()).unwrap()
+}
@@ -0,0 +1,145 @@
+mod common;
+
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+use multiversx_bls::*;
+
+use common::random_secret_key;
+
+// standard generators of the IETF and ZCash serialization, which is not the generator of the
+// public keys of this crate
+const G1_GENERATOR_HEX: &str = "97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb";
+const G2_GENERATOR_HEX: &str = "93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8";
+
+// points on the curves outside of the prime order subgroups
+const G1_NOT_IN_SUBGROUP_HEX: &str = "800000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004";
+const G2_NOT_IN_SUBGROUP_HEX: &str = "800000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002";
+
+// `g1` is `sec` times the `VRF_DST` hash of `msg` and `g2` is `sec` times the standard
+// generator, both computed and encoded by the zkcrypto bls12_381 crate
+#[test]
+fn test_compressed_vectors() {
+    let file = BufReader::new(File::open("tests/compressed.txt").unwrap());
+    let g2_generator = G2::from_compressed(&hex::decode(G2_GENERATOR_HEX).unwrap()).unwrap();
+    let mut sk = SecretKey::default();
+    let mut msg = Vec::new();
+
+    for l in file.lines() {
+        let line = l.unwrap();
+        let elements: Vec<&str> = line.split(' ').collect();
+        match elements[0] {
+            "sec" => sk = SecretKey::from_serialized(&hex::decode(elements[1]).unwrap()).unwrap(),
+            "msg" => msg = hex::decode(elements[1]).unwrap(),
+            "g1" => {
+                let (_, proof) = sk.vrf_prove(&msg).unwrap();
+                assert_eq!(
+                    hex::encode(proof.serialize_compressed().unwrap()),
+                    elements[1]
+                );
+                assert_eq!(
+                    G1::from_compressed(&hex::decode(elements[1]).unwrap()).unwrap(),
+                    proof
+                );
+            }
+            "g2" => {
+                let mut public_key = g2_generator;
+                public_key.mul_assign(&sk);
+                assert_eq!(
+                    hex::encode(public_key.serialize_compressed().unwrap()),
+                    elements[1]
+                );
+                assert_eq!(
+                    G2::from_compressed(&hex::decode(elements[1]).unwrap()).unwrap(),
+                    public_key
+                );
+            }
+            _ => (),
+        }
+    }
+}
+
+#[test]
+fn test_compressed_round_trip() {
+    for _ in 0..20 {
+        let sk = random_secret_key();
+        let pk = sk.get_public_key();
+        let sig = sk.sign(b"abc");
+
+        let buf = pk.serialize_compressed().unwrap();
+        assert_eq!(buf.len(), G2_COMPRESSED_SIZE);
+        assert_eq!(G2::from_compressed(&buf).unwrap(), pk);
+        let buf = sig.serialize_compressed().unwrap();
+        assert_eq!(buf.len(), G1_COMPRESSED_SIZE);
+        assert_eq!(G1::from_compressed(&buf).unwrap(), sig);
+    }
+
+    let g1_generator = hex::decode(G1_GENERATOR_HEX).unwrap();
+    assert_eq!(
+        G1::from_compressed(&g1_generator)
+            .unwrap()
+            .serialize_compressed()
+            .unwrap(),
+        g1_generator
+    );
+
+    let mut infinity = vec![0u8; G2_COMPRESSED_SIZE];
+    infinity[0] = 0xc0;
+    assert!(G2::from_compressed(&infinity).unwrap().is_zero());
+    assert_eq!(G2::default().serialize_compressed().unwrap(), infinity);
+    assert!(G1::from_compressed(&infinity[..G1_COMPRESSED_SIZE])
+        .unwrap()
+        .is_zero());
+}
+
+#[test]
+fn test_compressed_sign_flag() {
+    // the negated generator only differs by its sign flag
+    let generator = hex::decode(G2_GENERATOR_HEX).unwrap();
+    let mut negated = generator.clone();
+    negated[0] ^= 0x20;
+
+    let mut sum = G2::from_compressed(&generator).unwrap();
+    sum.add_assign(G2::from_compressed(&negated).unwrap());
+    assert!(sum.is_zero());
+}
+
+#[test]
+fn test_compressed_invalid() {
+    let generator = hex::decode(G2_GENERATOR_HEX).unwrap();
+    let mut uncompressed = generator.clone();
+    uncompressed[0] &= 0x7f;
+    let mut infinity_with_x = generator.clone();
+    infinity_with_x[0] |= 0x40;
+    let mut infinity_with_sign = vec![0u8; G2_COMPRESSED_SIZE];
+    infinity_with_sign[0] = 0xe0;
+    // x = p, above the field modulus
+    let mut non_canonical = hex::decode("9a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab").unwrap();
+    non_canonical.extend_from_slice(&generator[48..]);
+
+    for buf in [
+        generator[..95].to_vec(),
+        uncompressed,
+        infinity_with_x,
+        infinity_with_sign,
+        non_canonical,
+        hex::decode(G2_NOT_IN_SUBGROUP_HEX).unwrap(),
+        // the herumi serialization of a public key
+        SecretKey::from_hex_str("1")
+            .unwrap()
+            .get_public_key()
+            .serialize()
+            .unwrap(),
+    ] {
+        assert_eq!(G2::from_compressed(&buf), Err(BlsError::InvalidData));
+    }
+
+    assert_eq!(
+        G1::from_compressed(&hex::decode(G1_NOT_IN_SUBGROUP_HEX).unwrap()),
+        Err(BlsError::InvalidData)
+    );
+    assert_eq!(
+        G1::from_compressed(&hex::decode(&G1_GENERATOR_HEX[2..]).unwrap()),
+        Err(BlsError::InvalidData)
+    );
+}
@@ -0,0 +1,157 @@
+mod common;
+
+use multiversx_bls::*;
+use sha2::{Digest, Sha256};
+
+use common::{fixture_secret_key, random_secret_key};
+
+const SECRET_BE_HEX: &str = "174ee80db5febf45bb01f282165751a25383e3979c7f77eff56b7061bfac9f93";
+
+// the standard generator, and the fixture secret key times it, encoded by the zkcrypto
+// bls12_381 crate
+const G2_GENERATOR_HEX: &str = "93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8";
+const ZKCRYPTO_PUBLIC_KEY_HEX: &str = "95449267446c4c71f2715fd37773a80490640921668ec0e6d47681b327df54da68af70f69edb45dfbbd5d5ff451eef7217e0a8b0eb1886298e37bc7d8d3164c24b8d200b9bb3991b864959778b91bf00ff3e941a39802e52ca315019a6a150c0";
+
+// {1: 1, -1: 14, -2: x}
+fn public_cose_key_hex(pk: &G2) -> String {
+    format!(
+        "a30101200e215860{}",
+        hex::encode(pk.serialize_compressed().unwrap())
+    )
+}
+
+#[test]
+fn test_cose_key_round_trip() {
+    let sk = fixture_secret_key();
+    let pk = sk.get_public_key();
+
+    let public_cose_key = pk.to_cose_key().unwrap();
+    assert_eq!(hex::encode(&public_cose_key), public_cose_key_hex(&pk));
+    assert_eq!(G2::from_cose_key(&public_cose_key).unwrap(), pk);
+    assert_eq!(
+        SecretKey::from_cose_key(&public_cose_key),
+        Err(BlsError::InvalidCoseKey)
+    );
+
+    let standard_public_key = sk.get_standard_public_key().unwrap();
+    let private_cose_key = sk.to_cose_key().unwrap();
+    assert_eq!(
+        hex::encode(&private_cose_key),
+        format!(
+            "a4{}235820{SECRET_BE_HEX}",
+            &public_cose_key_hex(&standard_public_key)[2..]
+        )
+    );
+    assert_eq!(SecretKey::from_cose_key(&private_cose_key).unwrap(), sk);
+    assert_eq!(
+        G2::from_cose_key(&private_cose_key).unwrap(),
+        standard_public_key
+    );
+
+    // other labels, such as kid (2) and text labels, are ignored
+    let extended =
+        format!("a5{}0241006161f5", &public_cose_key_hex(&pk)[2..]).replace("6161f5", "616160");
+    assert_eq!(
+        G2::from_cose_key(&hex::decode(extended).unwrap()).unwrap(),
+        pk
+    );
+}
+
+#[test]
+fn test_cose_key_thumbprint() {
+    let pk = fixture_secret_key().get_public_key();
+    let expected = Sha256::digest(hex::decode(public_cose_key_hex(&pk)).unwrap()).to_vec();
+    assert_eq!(pk.cose_key_thumbprint().unwrap(), expected);
+}
+
+#[test]
+fn test_cose_key_interoperability() {
+    let mut pk = G2::from_compressed(&hex::decode(G2_GENERATOR_HEX).unwrap()).unwrap();
+    pk.mul_assign(&fixture_secret_key());
+
+    let cose_key = format!("a30101200e215860{ZKCRYPTO_PUBLIC_KEY_HEX}");
+    assert_eq!(hex::encode(pk.to_cose_key().unwrap()), cose_key);
+    assert_eq!(
+        G2::from_cose_key(&hex::decode(cose_key).unwrap()).unwrap(),
+        pk
+    );
+    assert_eq!(
+        hex::encode(pk.cose_key_thumbprint().unwrap()),
+        "32cea1513f4f7fd0904e2ccd37d32a548be13b8992d7f041c398f1898b4c42fe"
+    );
+}
+
+// A secret key as other tools see it: they derive `x` from `d` with the standard generator,
+// which is not the MultiversX public key.
+#[test]
+fn test_private_cose_key_interoperability() {
+    let sk = fixture_secret_key();
+    let cose_key = hex::decode(format!(
+        "a40101200e215860{ZKCRYPTO_PUBLIC_KEY_HEX}235820{SECRET_BE_HEX}"
+    ))
+    .unwrap();
+
+    assert_eq!(SecretKey::from_cose_key(&cose_key).unwrap(), sk);
+    assert_eq!(sk.to_cose_key().unwrap(), cose_key);
+    assert_ne!(G2::from_cose_key(&cose_key).unwrap(), sk.get_public_key());
+
+    // the MultiversX public key is never the `x` of `d`
+    let mismatch = format!(
+        "a4{}235820{SECRET_BE_HEX}",
+        &public_cose_key_hex(&sk.get_public_key())[2..]
+    );
+    assert_eq!(
+        SecretKey::from_cose_key(&hex::decode(mismatch).unwrap()),
+        Err(BlsError::PublicKeyMismatch)
+    );
+}
+
+#[test]
+fn test_cose_key_invalid() {
+    let sk = fixture_secret_key();
+    let pk = sk.get_public_key();
+    let public_hex = public_cose_key_hex(&pk);
+
+    let other = random_secret_key();
+    let mismatch = format!(
+        "a4{}235820{}",
+        &public_hex[2..],
+        hex::encode(
+            other
+                .to_cose_key()
+                .unwrap()
+                .split_off(pk.to_cose_key().unwrap().len() + 3)
+        )
+    );
+    assert_eq!(
+        SecretKey::from_cose_key(&hex::decode(mismatch).unwrap()),
+        Err(BlsError::PublicKeyMismatch)
+    );
+
+    let invalid = [
+        // EC2 key type
+        public_hex.replacen("a30101", "a30102", 1),
+        // BLS12-381 G1 curve
+        public_hex.replacen("200e", "200d", 1),
+        // duplicate label
+        format!("a4{}0101", &public_hex[2..]),
+        // missing x
+        "a20101200e".to_string(),
+        // trailing data
+        format!("{public_hex}00"),
+        // truncated
+        public_hex[..public_hex.len() - 2].to_string(),
+        // zero secret key
+        format!("a4{}235820{}", &public_hex[2..], "00".repeat(32)),
+        // not a map
+        "80".to_string(),
+        // herumi serialization of the public key
+        format!("a30101200e215860{}", hex::encode(pk.serialize().unwrap())),
+    ];
+    for cose_key in invalid {
+        assert_eq!(
+            SecretKey::from_cose_key(&hex::decode(cose_key).unwrap()),
+            Err(BlsError::InvalidCoseKey)
+        );
+    }
+}
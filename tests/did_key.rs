@@ -0,0 +1,144 @@
+mod common;
+
+use multiversx_bls::*;
+
+use common::fixture_secret_key;
+
+// BLS12-381 G2 test vectors of the did:key specification
+const SPEC_G2_DID_KEYS: [&str; 2] = [
+    "did:key:zUC7K4ndUaGZgV7Cp2yJy6JtMoUHY6u7tkcSYUvPrEidqBmLCTLmi6d5WvwnUqejscAkERJ3bfjEiSYtdPkRSE8kSa11hFBr4sTgnbZ95SJj19PN2jdvJjyzpSZgxkyyxNnBNnY",
+    "did:key:zUC7EK3ZakmukHhuncwkbySmomv3FmrkmS36E4Ks5rsb6VQSRpoCrx6Hb8e2Nk6UvJFSdyw9NK1scFXJp21gNNYFjVWNgaqyGnkyhtagagCpQb5B7tagJu3HDbjQ8h5ypoHjwBb",
+];
+
+// a `G1` point encoded by the zkcrypto bls12_381 crate, see tests/compressed.txt, and its
+// did:key
+const ZKCRYPTO_G1_HEX: &str = "ac0f493a74e95b756cd10d6375d9aa3a645d51fb142aaf80b6cda69c1d9198827747ebab3a5ae0e936a6c0fb15229557";
+const ZKCRYPTO_G1_DID_KEY: &str =
+    "did:key:z3tEGDRgYbnTN8ZEkzmhhKCRfJNAdgNUxbQwzq9EGEChWphgfGBBgBzusGC3XLsxFh8SfY";
+
+fn fixture_public_key() -> G2 {
+    fixture_secret_key().get_public_key()
+}
+
+#[test]
+fn test_multibase_round_trip() {
+    let pk = fixture_public_key();
+    let multibase = pk.to_multibase().unwrap();
+    assert!(multibase.starts_with('z'));
+
+    let decoded = bs58::decode(&multibase[1..]).into_vec().unwrap();
+    assert_eq!(decoded[..2], [0xeb, 0x01]);
+    assert_eq!(decoded[2..], pk.serialize_compressed().unwrap());
+    assert_eq!(G2::from_multibase(&multibase).unwrap(), pk);
+
+    let sig = fixture_secret_key().sign(b"abc");
+    let multibase = sig.to_multibase().unwrap();
+    let decoded = bs58::decode(&multibase[1..]).into_vec().unwrap();
+    assert_eq!(decoded[..2], [0xea, 0x01]);
+    assert_eq!(G1::from_multibase(&multibase).unwrap(), sig);
+
+    // the multicodec must match the group
+    assert_eq!(
+        G2::from_multibase(&multibase),
+        Err(BlsError::InvalidMultibase)
+    );
+}
+
+#[test]
+fn test_did_key_vectors() {
+    for did in SPEC_G2_DID_KEYS {
+        let pk = G2::from_did_key(did).unwrap();
+        assert_eq!(pk.to_did_key().unwrap(), did);
+        assert_eq!(
+            resolve_did_key(did).unwrap().verification_method[0].key_type,
+            "Bls12381G2Key2020"
+        );
+    }
+
+    let key = G1::from_compressed(&hex::decode(ZKCRYPTO_G1_HEX).unwrap()).unwrap();
+    assert_eq!(key.to_did_key().unwrap(), ZKCRYPTO_G1_DID_KEY);
+    assert_eq!(G1::from_did_key(ZKCRYPTO_G1_DID_KEY).unwrap(), key);
+    assert_eq!(
+        G1::from_did_key(SPEC_G2_DID_KEYS[0]),
+        Err(BlsError::InvalidMultibase)
+    );
+    assert_eq!(
+        G1::from_did_key(&ZKCRYPTO_G1_DID_KEY.replace("did:key:", "did:web:")),
+        Err(BlsError::InvalidDid)
+    );
+}
+
+#[test]
+fn test_multibase_invalid() {
+    let pk = fixture_public_key();
+    let key = pk.serialize_compressed().unwrap();
+    let encode = |buf: &[u8]| format!("z{}", bs58::encode(buf).into_string());
+
+    let invalid = [
+        // base64url multibase
+        format!("u{}", &pk.to_multibase().unwrap()[1..]),
+        "z0OIl".to_string(),
+        encode(&[&[0xeb, 0x81, 0x00][..], &key].concat()),
+        encode(&[&[0xeb, 0x01][..], &key[..95]].concat()),
+        encode(&[&[0xeb, 0x01][..], &[0u8; 96]].concat()),
+        encode(&[0xeb]),
+        // herumi serialization of the key
+        encode(&[&[0xeb, 0x01][..], &pk.serialize().unwrap()].concat()),
+    ];
+    for multibase in invalid {
+        assert_eq!(
+            G2::from_multibase(&multibase),
+            Err(BlsError::InvalidMultibase)
+        );
+    }
+}
+
+#[test]
+fn test_resolve_did_key() {
+    let pk = fixture_public_key();
+    let did = pk.to_did_key().unwrap();
+    let fingerprint = pk.to_multibase().unwrap();
+    assert_eq!(did, format!("did:key:{fingerprint}"));
+    assert_eq!(G2::from_did_key(&did).unwrap(), pk);
+
+    let document = resolve_did_key(&did).unwrap();
+    let method_id = format!("{did}#{fingerprint}");
+    assert_eq!(document.id, did);
+    assert_eq!(document.context[0], "https://www.w3.org/ns/did/v1");
+    assert_eq!(
+        document.verification_method,
+        vec![VerificationMethod {
+            id: method_id.clone(),
+            key_type: "Bls12381G2Key2020".to_string(),
+            controller: did.clone(),
+            public_key_base58: bs58::encode(pk.serialize_compressed().unwrap()).into_string(),
+        }]
+    );
+    for relationship in [
+        &document.authentication,
+        &document.assertion_method,
+        &document.capability_delegation,
+        &document.capability_invocation,
+    ] {
+        assert_eq!(relationship, &vec![method_id.clone()]);
+    }
+    let json = document.to_json().unwrap();
+    assert!(json.contains(r#""publicKeyBase58""#));
+    assert!(json.contains(r#""@context""#));
+
+    let sig = fixture_secret_key().sign(b"abc");
+    let document = resolve_did_key(&sig.to_did_key().unwrap()).unwrap();
+    assert_eq!(
+        document.verification_method[0].key_type,
+        "Bls12381G1Key2020"
+    );
+
+    assert_eq!(
+        resolve_did_key(&did.replace("did:key:", "did:web:")),
+        Err(BlsError::InvalidDid)
+    );
+    assert_eq!(
+        resolve_did_key(&format!("{did}#{fingerprint}")),
+        Err(BlsError::InvalidDid)
+    );
+}
@@ -0,0 +1,228 @@
+mod common;
+
+use multiversx_bls::*;
+
+use common::random_secret_key;
+
+const SLOT: Slot = Slot {
+    epoch: 1207,
+    round: 24_164_301,
+    kind: 2,
+};
+
+const HEADER_SLOT_SIZE: usize = 4 + 8 + 1;
+
+/// return a test header of `slot`: the big-endian epoch and round, the kind, then `body`
+fn header(slot: Slot, body: &[u8]) -> Vec<u8> {
+    let mut header = Vec::with_capacity(HEADER_SLOT_SIZE + body.len());
+    header.extend_from_slice(&slot.epoch.to_be_bytes());
+    header.extend_from_slice(&slot.round.to_be_bytes());
+    header.push(slot.kind);
+    header.extend_from_slice(body);
+    header
+}
+
+/// return the slot of the test header `header`
+fn slot_of(header: &[u8]) -> Result<Slot, BlsError> {
+    if header.len() < HEADER_SLOT_SIZE {
+        return Err(BlsError::InvalidData);
+    }
+
+    Ok(Slot {
+        epoch: u32::from_be_bytes(header[..4].try_into().unwrap()),
+        round: u64::from_be_bytes(header[4..12].try_into().unwrap()),
+        kind: header[12],
+    })
+}
+
+#[test]
+fn test_equivocation() {
+    let sk = random_secret_key();
+    let pk = sk.get_public_key();
+    let (header_a, header_b) = (header(SLOT, b"a"), header(SLOT, b"b"));
+    let (signature_a, signature_b) = (sk.sign(&header_a), sk.sign(&header_b));
+
+    let equivocation = Equivocation::new(
+        pk,
+        SLOT,
+        (&header_b, signature_b),
+        (&header_a, signature_a),
+        slot_of,
+    )
+    .unwrap();
+    equivocation.verify(slot_of).unwrap();
+    assert_eq!(equivocation.public_key(), &pk);
+    assert_eq!(equivocation.slot(), SLOT);
+    assert_eq!(equivocation.payloads(), (&header_a[..], &header_b[..]));
+    assert_eq!(equivocation.signatures(), (&signature_a, &signature_b));
+
+    // the serialization does not depend on the order of the payloads
+    let buf = equivocation.serialize().unwrap();
+    let swapped = Equivocation::new(
+        pk,
+        SLOT,
+        (&header_a, signature_a),
+        (&header_b, signature_b),
+        slot_of,
+    )
+    .unwrap();
+    assert_eq!(swapped.serialize().unwrap(), buf);
+    assert_eq!(buf.len(), 96 + 4 + 8 + 1 + 2 * (4 + 14 + 48));
+    assert_eq!(hex::encode(&buf[96..109]), "000004b7000000000170b7cd02");
+    assert_eq!(
+        Equivocation::from_serialized(&buf, slot_of).unwrap(),
+        equivocation
+    );
+}
+
+#[test]
+fn test_evidence_of_signing_protections() {
+    let sk = random_secret_key();
+    let (header_a, header_b) = (header(SLOT, b"a"), header(SLOT, b"b"));
+
+    // a key used by two nodes, each with its own records
+    let signature_a = SigningProtection::new().sign(&sk, SLOT, &header_a).unwrap();
+    let signature_b = SigningProtection::new().sign(&sk, SLOT, &header_b).unwrap();
+
+    Equivocation::new(
+        sk.get_public_key(),
+        SLOT,
+        (&header_a, signature_a),
+        (&header_b, signature_b),
+        slot_of,
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_reject_evidence() {
+    let sk = random_secret_key();
+    let pk = sk.get_public_key();
+    let (header_a, header_b) = (header(SLOT, b"a"), header(SLOT, b"b"));
+    let (signature_a, signature_b) = (sk.sign(&header_a), sk.sign(&header_b));
+
+    assert_eq!(
+        Equivocation::new(
+            pk,
+            SLOT,
+            (&header_a, signature_a),
+            (&header_a, signature_a),
+            slot_of
+        ),
+        Err(BlsError::InvalidEvidence)
+    );
+    assert_eq!(
+        Equivocation::new(
+            pk,
+            SLOT,
+            (&header_a, signature_a),
+            (&header_b, random_secret_key().sign(&header_b)),
+            slot_of
+        ),
+        Err(BlsError::InvalidSignature)
+    );
+    assert_eq!(
+        Equivocation::new(
+            pk,
+            SLOT,
+            (&header_a, signature_a),
+            (&header_b, G1::default()),
+            slot_of
+        ),
+        Err(BlsError::InvalidData)
+    );
+
+    let buf = Equivocation::new(
+        pk,
+        SLOT,
+        (&header_a, signature_a),
+        (&header_b, signature_b),
+        slot_of,
+    )
+    .unwrap()
+    .serialize()
+    .unwrap();
+    assert_eq!(
+        Equivocation::from_serialized(&buf[..buf.len() - 1], slot_of),
+        Err(BlsError::BadSize)
+    );
+    let mut extended = buf.clone();
+    extended.push(0);
+    assert_eq!(
+        Equivocation::from_serialized(&extended, slot_of),
+        Err(BlsError::InvalidData)
+    );
+
+    // payloads out of order
+    let entry_size = 4 + header_a.len() + 48;
+    let mut reordered = buf[..109].to_vec();
+    reordered.extend_from_slice(&buf[109 + entry_size..]);
+    reordered.extend_from_slice(&buf[109..109 + entry_size]);
+    assert_eq!(
+        Equivocation::from_serialized(&reordered, slot_of),
+        Err(BlsError::InvalidEvidence)
+    );
+
+    // evidence claimed for another payload
+    let mut forged = buf.clone();
+    forged[109 + 4 + HEADER_SLOT_SIZE] ^= 1;
+    assert_eq!(
+        Equivocation::from_serialized(&forged, slot_of),
+        Err(BlsError::InvalidSignature)
+    );
+}
+
+#[test]
+fn test_reject_signatures_of_other_slots() {
+    let sk = random_secret_key();
+    let pk = sk.get_public_key();
+    let next_round = Slot {
+        round: SLOT.round + 1,
+        ..SLOT
+    };
+    let (header_a, header_b) = (header(SLOT, b"a"), header(next_round, b"b"));
+
+    // honest signatures of two rounds are no evidence for either round
+    for slot in [SLOT, next_round] {
+        assert_eq!(
+            Equivocation::new(
+                pk,
+                slot,
+                (&header_a, sk.sign(&header_a)),
+                (&header_b, sk.sign(&header_b)),
+                slot_of
+            ),
+            Err(BlsError::InvalidEvidence)
+        );
+    }
+
+    // nor are signatures of payloads without a slot
+    assert_eq!(
+        Equivocation::new(
+            pk,
+            SLOT,
+            (b"header a", sk.sign(b"header a")),
+            (b"header b", sk.sign(b"header b")),
+            slot_of
+        ),
+        Err(BlsError::InvalidEvidence)
+    );
+
+    // evidence claimed for another slot
+    let header_b = header(SLOT, b"b");
+    let mut buf = Equivocation::new(
+        pk,
+        SLOT,
+        (&header_a, sk.sign(&header_a)),
+        (&header_b, sk.sign(&header_b)),
+        slot_of,
+    )
+    .unwrap()
+    .serialize()
+    .unwrap();
+    buf[96 + 4 + 7] ^= 1;
+    assert_eq!(
+        Equivocation::from_serialized(&buf, slot_of),
+        Err(BlsError::InvalidEvidence)
+    );
+}
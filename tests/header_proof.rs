@@ -0,0 +1,110 @@
+use multiversx_bls::*;
+use serde_json::Value;
+
+const EPOCH: u32 = 1207;
+
+struct Fixture {
+    sks: Vec<SecretKey>,
+    consensus_group: ValidatorSet,
+    proof: HeaderProof,
+}
+
+fn read_fixture() -> Fixture {
+    let json: Value =
+        serde_json::from_str(&std::fs::read_to_string("tests/header_proof.json").unwrap()).unwrap();
+    let hex_items = |key: &str| -> Vec<Vec<u8>> {
+        json[key]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|item| hex::decode(item.as_str().unwrap()).unwrap())
+            .collect()
+    };
+
+    Fixture {
+        sks: hex_items("secretKeys")
+            .iter()
+            .map(|buf| SecretKey::from_serialized(buf).unwrap())
+            .collect(),
+        consensus_group: ValidatorSet::new(
+            hex_items("consensusGroup")
+                .iter()
+                .map(|buf| G2::from_serialized(buf).unwrap())
+                .collect(),
+        )
+        .unwrap(),
+        proof: HeaderProof::from_json(&json["proof"].to_string()).unwrap(),
+    }
+}
+
+// return `proof` signed by the validators at `signers`
+fn signed_by(fixture: &Fixture, signers: &[usize]) -> HeaderProof {
+    let header_hash = fixture.proof.header_hash().unwrap();
+    let mut signature = G1::default();
+    for &i in signers {
+        signature.add_assign(fixture.sks[i].sign(&header_hash));
+    }
+
+    HeaderProof {
+        pub_keys_bitmap: hex::encode(fixture.consensus_group.bitmap(signers).unwrap()),
+        aggregated_signature: hex::encode(signature.serialize().unwrap()),
+        ..fixture.proof.clone()
+    }
+}
+
+#[test]
+fn test_verify_header_proof() {
+    let fixture = read_fixture();
+    fixture
+        .proof
+        .verify(EPOCH, &fixture.consensus_group)
+        .unwrap();
+    assert_eq!(
+        HeaderProof::from_json(&fixture.proof.to_json().unwrap()).unwrap(),
+        fixture.proof
+    );
+
+    signed_by(&fixture, &[0, 1, 2, 3, 4, 5, 6])
+        .verify(EPOCH, &fixture.consensus_group)
+        .unwrap();
+}
+
+#[test]
+fn test_verify_header_proof_rules() {
+    let fixture = read_fixture();
+    let group = &fixture.consensus_group;
+
+    assert_eq!(
+        fixture.proof.verify(EPOCH + 1, group),
+        Err(BlsError::EpochMismatch)
+    );
+    assert_eq!(
+        signed_by(&fixture, &[0, 1, 2, 3]).verify(EPOCH, group),
+        Err(BlsError::ThresholdNotReached)
+    );
+    assert_eq!(
+        signed_by(&fixture, &[1, 2, 3, 4, 5]).verify(EPOCH, group),
+        Err(BlsError::LeaderNotSigned)
+    );
+
+    // bitmap selecting other signers than the aggregated signature
+    let mut proof = signed_by(&fixture, &[0, 1, 2, 3, 4]);
+    proof.pub_keys_bitmap = hex::encode(group.bitmap(&[0, 1, 2, 3, 5]).unwrap());
+    assert_eq!(proof.verify(EPOCH, group), Err(BlsError::InvalidSignature));
+
+    let mut proof = fixture.proof.clone();
+    proof.pub_keys_bitmap = "ff".to_string();
+    assert_eq!(proof.verify(EPOCH, group), Err(BlsError::InvalidBitmap));
+    proof.pub_keys_bitmap = "5700".to_string();
+    assert_eq!(proof.verify(EPOCH, group), Err(BlsError::InvalidBitmap));
+
+    let mut proof = fixture.proof.clone();
+    proof.header_hash.pop();
+    assert_eq!(proof.verify(EPOCH, group), Err(BlsError::InvalidData));
+    proof.header_hash.pop();
+    assert_eq!(proof.verify(EPOCH, group), Err(BlsError::BadSize));
+
+    let mut proof = fixture.proof.clone();
+    proof.aggregated_signature = hex::encode([0u8; 48]);
+    assert_eq!(proof.verify(EPOCH, group), Err(BlsError::InvalidData));
+}
@@ -0,0 +1,72 @@
+mod common;
+
+use multiversx_bls::*;
+
+use common::{random_secret_key, random_secret_keys, MINUS_ONE_HEX};
+
+const MSG: &[u8] = b"header hash";
+
+fn signatures(sks: &[SecretKey]) -> (Vec<G2>, Vec<G1>) {
+    (
+        sks.iter().map(|sk| sk.get_public_key()).collect(),
+        sks.iter().map(|sk| sk.sign(MSG)).collect(),
+    )
+}
+
+#[test]
+fn test_find_invalid_shares() {
+    let sks = random_secret_keys(64);
+    let (public_keys, mut shares) = signatures(&sks);
+    assert!(find_invalid_shares(&public_keys, &shares, MSG)
+        .unwrap()
+        .is_empty());
+
+    shares[40] = sks[40].sign(b"another header");
+    shares[3] = sks[4].sign(MSG);
+    shares[63] = G1::default();
+    assert_eq!(
+        find_invalid_shares(&public_keys, &shares, MSG).unwrap(),
+        [3, 40, 63]
+    );
+
+    // only share 40 is valid for the other message
+    assert_eq!(
+        find_invalid_shares(&public_keys, &shares, b"another header").unwrap(),
+        (0..64).filter(|i| *i != 40).collect::<Vec<usize>>()
+    );
+}
+
+#[test]
+fn test_find_cancelling_shares() {
+    let sks = random_secret_keys(8);
+    let (public_keys, mut shares) = signatures(&sks);
+
+    // shares 2 and 5 are replaced by s2 + s5 - x and x: their sum still verifies
+    let scalar = random_secret_key();
+    let mut x = sks[0].sign(MSG);
+    x.mul_assign(&scalar);
+    let mut minus_x = x;
+    minus_x.mul_assign(&SecretKey::from_hex_str(MINUS_ONE_HEX).unwrap());
+    let share = shares[5];
+    shares[2].add_assign(share);
+    shares[2].add_assign(minus_x);
+    shares[5] = x;
+
+    let mut aggregated = G1::default();
+    aggregated.aggregate(&shares);
+    assert!(aggregated.fast_aggregate_verify(&public_keys, MSG));
+    assert_eq!(
+        find_invalid_shares(&public_keys, &shares, MSG).unwrap(),
+        [2, 5]
+    );
+}
+
+#[test]
+fn test_find_invalid_shares_sizes() {
+    let (public_keys, shares) = signatures(&random_secret_keys(3));
+    assert!(find_invalid_shares(&[], &[], MSG).unwrap().is_empty());
+    assert_eq!(
+        find_invalid_shares(&public_keys[1..], &shares, MSG),
+        Err(BlsError::BadSize)
+    );
+}
@@ -0,0 +1,146 @@
+mod common;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use multiversx_bls::*;
+use sha2::{Digest, Sha256};
+
+use common::{fixture_secret_key, random_secret_key};
+
+const SECRET_BE_HEX: &str = "174ee80db5febf45bb01f282165751a25383e3979c7f77eff56b7061bfac9f93";
+
+// the standard generator, and the fixture secret key times it, encoded by the zkcrypto
+// bls12_381 crate
+const G2_GENERATOR_HEX: &str = "93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8";
+const ZKCRYPTO_PUBLIC_KEY_HEX: &str = "95449267446c4c71f2715fd37773a80490640921668ec0e6d47681b327df54da68af70f69edb45dfbbd5d5ff451eef7217e0a8b0eb1886298e37bc7d8d3164c24b8d200b9bb3991b864959778b91bf00ff3e941a39802e52ca315019a6a150c0";
+
+#[test]
+fn test_jwk_round_trip() {
+    let sk = fixture_secret_key();
+    let pk = sk.get_public_key();
+    let x = URL_SAFE_NO_PAD.encode(pk.serialize_compressed().unwrap());
+    let d = URL_SAFE_NO_PAD.encode(hex::decode(SECRET_BE_HEX).unwrap());
+
+    let public_jwk = pk.to_jwk().unwrap();
+    assert_eq!(
+        public_jwk.to_json().unwrap(),
+        format!(r#"{{"kty":"OKP","crv":"Bls12381G2","x":"{x}"}}"#)
+    );
+    assert_eq!(public_jwk.public_key().unwrap(), pk);
+    assert_eq!(public_jwk.secret_key(), Err(BlsError::InvalidJwk));
+
+    let private_jwk = sk.to_jwk().unwrap();
+    assert_eq!(private_jwk.d.as_deref(), Some(d.as_str()));
+    let parsed = Jwk::from_json(&private_jwk.to_json().unwrap()).unwrap();
+    assert_eq!(parsed.secret_key().unwrap(), sk);
+    assert_eq!(
+        parsed.public_key().unwrap(),
+        sk.get_standard_public_key().unwrap()
+    );
+
+    // other members, such as `kid` and `use`, are ignored
+    let extended = format!(r#"{{"kty":"OKP","crv":"Bls12381G2","x":"{x}","kid":"k","use":"sig"}}"#);
+    assert_eq!(Jwk::from_json(&extended).unwrap(), public_jwk);
+}
+
+#[test]
+fn test_jwk_thumbprint() {
+    let sk = fixture_secret_key();
+    let pk = sk.get_public_key();
+    let x = URL_SAFE_NO_PAD.encode(pk.serialize_compressed().unwrap());
+    let members = format!(r#"{{"crv":"Bls12381G2","kty":"OKP","x":"{x}"}}"#);
+
+    let expected = Sha256::digest(members.as_bytes()).to_vec();
+    assert_eq!(pk.to_jwk().unwrap().thumbprint().unwrap(), expected);
+    // the secret key is not part of the thumbprint, which is the one of its standard public key
+    let standard_public_key = sk.get_standard_public_key().unwrap();
+    assert_eq!(
+        sk.to_jwk().unwrap().thumbprint().unwrap(),
+        standard_public_key.to_jwk().unwrap().thumbprint().unwrap()
+    );
+    assert_ne!(sk.to_jwk().unwrap().thumbprint().unwrap(), expected);
+}
+
+#[test]
+fn test_jwk_interoperability() {
+    let mut pk = G2::from_compressed(&hex::decode(G2_GENERATOR_HEX).unwrap()).unwrap();
+    pk.mul_assign(&fixture_secret_key());
+
+    let jwk = pk.to_jwk().unwrap();
+    assert_eq!(
+        jwk.x,
+        URL_SAFE_NO_PAD.encode(hex::decode(ZKCRYPTO_PUBLIC_KEY_HEX).unwrap())
+    );
+    assert_eq!(
+        hex::encode(jwk.thumbprint().unwrap()),
+        "543cc11783f5a8484bd356d5c9176c845ba2285ee5b4e7b683177090ebbad0bd"
+    );
+    assert_eq!(
+        Jwk::from_json(&jwk.to_json().unwrap())
+            .unwrap()
+            .public_key()
+            .unwrap(),
+        pk
+    );
+}
+
+// A secret key as other tools see it: they derive `x` from `d` with the standard generator,
+// which is not the MultiversX public key.
+#[test]
+fn test_private_jwk_interoperability() {
+    let sk = fixture_secret_key();
+    let x = URL_SAFE_NO_PAD.encode(hex::decode(ZKCRYPTO_PUBLIC_KEY_HEX).unwrap());
+    let d = URL_SAFE_NO_PAD.encode(hex::decode(SECRET_BE_HEX).unwrap());
+    let json = format!(r#"{{"kty":"OKP","crv":"Bls12381G2","x":"{x}","d":"{d}"}}"#);
+
+    let jwk = Jwk::from_json(&json).unwrap();
+    assert_eq!(jwk.secret_key().unwrap(), sk);
+    assert_eq!(sk.to_jwk().unwrap().to_json().unwrap(), json);
+    assert_ne!(jwk.public_key().unwrap(), sk.get_public_key());
+    assert_eq!(
+        hex::encode(jwk.thumbprint().unwrap()),
+        "543cc11783f5a8484bd356d5c9176c845ba2285ee5b4e7b683177090ebbad0bd"
+    );
+
+    // the MultiversX public key is never the `x` of `d`
+    let mut k = jwk.clone();
+    k.x = URL_SAFE_NO_PAD.encode(sk.get_public_key().serialize_compressed().unwrap());
+    assert_eq!(k.secret_key(), Err(BlsError::PublicKeyMismatch));
+}
+
+#[test]
+fn test_jwk_invalid() {
+    let sk = fixture_secret_key();
+    let jwk = sk.to_jwk().unwrap();
+
+    let mut k = jwk.clone();
+    k.kty = "EC".to_string();
+    assert_eq!(k.public_key(), Err(BlsError::InvalidJwk));
+
+    let mut k = jwk.clone();
+    k.crv = "Bls12381G1".to_string();
+    assert_eq!(k.thumbprint(), Err(BlsError::InvalidJwk));
+
+    let mut k = jwk.clone();
+    k.x.push('=');
+    assert_eq!(k.public_key(), Err(BlsError::InvalidJwk));
+
+    let mut k = jwk.clone();
+    k.d = Some(URL_SAFE_NO_PAD.encode([0u8; 32]));
+    assert_eq!(k.secret_key(), Err(BlsError::InvalidJwk));
+
+    let other = random_secret_key();
+    let mut k = jwk.clone();
+    k.d = other.to_jwk().unwrap().d;
+    assert_eq!(k.secret_key(), Err(BlsError::PublicKeyMismatch));
+
+    assert_eq!(
+        Jwk::from_json(r#"{"kty":"OKP","crv":"Bls12381G2"}"#),
+        Err(BlsError::InvalidJwk)
+    );
+
+    // the herumi serialization is not a compressed point
+    let mut k = jwk.clone();
+    k.x = URL_SAFE_NO_PAD.encode(sk.get_public_key().serialize().unwrap());
+    assert_eq!(k.public_key(), Err(BlsError::InvalidJwk));
+}
@@ -0,0 +1,74 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+};
+
+use multiversx_bls::*;
+
+#[test]
+fn test_eip2333_vectors() {
+    let f = File::open("tests/key_derivation.txt").unwrap();
+    let file = BufReader::new(&f);
+    let mut master = SecretKey::default();
+    let mut index = 0u32;
+
+    for l in file.lines() {
+        let line = l.unwrap();
+        let elements: Vec<&str> = line.split_whitespace().collect();
+        match elements[0] {
+            "seed" => master = SecretKey::from_seed(&hex::decode(elements[1]).unwrap()).unwrap(),
+            "master" => assert_eq!(master, SecretKey::from_hex_str(elements[1]).unwrap()),
+            "index" => index = elements[1].parse().unwrap(),
+            "child" => {
+                let child = master.derive_child(index).unwrap();
+                assert_eq!(child, SecretKey::from_hex_str(elements[1]).unwrap());
+            }
+            _ => (),
+        }
+    }
+}
+
+#[test]
+fn test_derive_path() {
+    let seed = [7u8; 32];
+    let master = SecretKey::from_seed(&seed).unwrap();
+
+    let expected = master
+        .derive_child(12381)
+        .and_then(|k| k.derive_child(3600))
+        .and_then(|k| k.derive_child(0))
+        .and_then(|k| k.derive_child(0))
+        .unwrap();
+
+    assert_eq!(master.derive_path("m/12381/3600/0/0").unwrap(), expected);
+    assert_eq!(
+        SecretKey::from_seed_and_path(&seed, "m/12381/3600/0/0").unwrap(),
+        expected
+    );
+    assert_eq!(master.derive_path("m").unwrap(), master);
+}
+
+#[test]
+fn test_derivation_errors() {
+    assert_eq!(SecretKey::from_seed(&[0u8; 31]), Err(BlsError::BadSize));
+
+    assert_eq!(
+        parse_derivation_path("m/12381/3600/0/0"),
+        Ok(vec![12381, 3600, 0, 0])
+    );
+    for path in [
+        "",
+        "12381/3600",
+        "m/",
+        "m//0",
+        "m/-1",
+        "m/+1",
+        "m/4294967296",
+        "m/0'",
+    ] {
+        assert_eq!(
+            parse_derivation_path(path),
+            Err(BlsError::InvalidDerivationPath)
+        );
+    }
+}
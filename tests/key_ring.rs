@@ -0,0 +1,121 @@
+mod common;
+
+use std::fs;
+
+use multiversx_bls::*;
+
+use common::random_secret_key;
+
+const SECRET_KEYS_HEX: [&str; 3] = [
+    "939facbf61706bf5ef777f9c97e38353a251571682f201bb45bffeb50de84e17",
+    "d5b3e46937e48d05d86b030654544ba488e34a51067e61a4ade323ddb7a02a5b",
+    "098b86c79ad66b0cc945a714bc9892373f024ccab4cd667cdfd295c650d6fe5b",
+];
+
+fn fixture_secret_keys() -> Vec<SecretKey> {
+    SECRET_KEYS_HEX
+        .iter()
+        .map(|x| SecretKey::from_serialized(&hex::decode(x).unwrap()).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_key_ring_load_and_sign() {
+    let key_ring = KeyRing::load("tests/allValidatorsKeys.pem").unwrap();
+    assert_eq!(key_ring.len(), 3);
+
+    let msg = "abc".as_bytes();
+    for sk in fixture_secret_keys() {
+        let pk = sk.get_public_key();
+        assert!(key_ring.contains(&pk));
+        assert_eq!(key_ring.sign(&pk, msg).unwrap(), sk.sign(msg));
+    }
+
+    let unknown = random_secret_key();
+    assert_eq!(
+        key_ring.sign(&unknown.get_public_key(), msg),
+        Err(BlsError::UnknownPublicKey)
+    );
+}
+
+#[test]
+fn test_key_ring_checked_signing() {
+    let mut key_ring = KeyRing::load("tests/allValidatorsKeys.pem").unwrap();
+    assert!(!key_ring.checked_signing());
+    key_ring.set_checked_signing(true);
+    assert!(key_ring.checked_signing());
+
+    let msg = "abc".as_bytes();
+    for sk in fixture_secret_keys() {
+        let pk = sk.get_public_key();
+        assert_eq!(key_ring.sign(&pk, msg).unwrap(), sk.sign(msg));
+    }
+}
+
+#[test]
+fn test_key_ring_add_remove() {
+    let mut key_ring = KeyRing::new();
+    let sks = fixture_secret_keys();
+
+    let pk = key_ring.add(sks[0]).unwrap();
+    assert_eq!(pk, sks[0].get_public_key());
+    assert_eq!(key_ring.add(sks[0]), Err(BlsError::DuplicatePublicKey));
+    assert_eq!(
+        key_ring.add(SecretKey::default()),
+        Err(BlsError::InvalidData)
+    );
+
+    key_ring.add(sks[1]).unwrap();
+    assert_eq!(key_ring.public_keys().len(), 2);
+
+    key_ring.remove(&pk).unwrap();
+    assert!(!key_ring.contains(&pk));
+    assert_eq!(key_ring.remove(&pk), Err(BlsError::UnknownPublicKey));
+    assert_eq!(key_ring.len(), 1);
+    assert_eq!(key_ring.reload(), Err(BlsError::InvalidData));
+}
+
+#[test]
+fn test_key_ring_reload() {
+    let path = std::env::temp_dir().join(format!("allValidatorsKeys-{}.pem", std::process::id()));
+    fs::copy("tests/allValidatorsKeys.pem", &path).unwrap();
+
+    let mut key_ring = KeyRing::load(&path).unwrap();
+    let extra = random_secret_key();
+    key_ring.add(extra).unwrap();
+    key_ring.save(&path).unwrap();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    let mut reloaded = KeyRing::load(&path).unwrap().with_checked_signing(true);
+    assert_eq!(reloaded.len(), 4);
+
+    fs::write(&path, pem::encode_all(&fixture_secret_keys()[..1]).unwrap()).unwrap();
+    reloaded.reload().unwrap();
+    assert_eq!(reloaded.len(), 1);
+    assert!(reloaded.checked_signing());
+
+    fs::write(&path, "not a pem file").unwrap();
+    assert_eq!(reloaded.reload(), Err(BlsError::InvalidPem));
+    assert_eq!(reloaded.len(), 1);
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_key_ring_export() {
+    let key_ring = KeyRing::load("tests/allValidatorsKeys.pem").unwrap();
+    let sk = fixture_secret_keys()[2];
+    let pk = sk.get_public_key();
+
+    assert_eq!(key_ring.export_secret_key(&pk).unwrap(), sk);
+    assert_eq!(pem::decode(&key_ring.export(&pk).unwrap()).unwrap(), sk);
+
+    let debug = format!("{key_ring:?}");
+    assert!(debug.contains(&hex::encode(pk.serialize().unwrap())));
+    assert!(!debug.contains(SECRET_KEYS_HEX[2]));
+}
@@ -0,0 +1,165 @@
+mod common;
+
+use std::fs;
+
+use multiversx_bls::*;
+
+use common::random_secret_key;
+
+// EIP-2335 test password, NFKD-normalized to "testpassword🔑"
+const PASSWORD: &str = "𝔱𝔢𝔰𝔱𝔭𝔞𝔰𝔰𝔴𝔬𝔯𝔡🔑";
+const SECRET_HEX: &str = "000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f";
+
+const LIGHT_KDF: KeystoreKdf = KeystoreKdf::Pbkdf2 { c: 16 };
+
+// The EIP-2335 vectors, with `pubkey` replaced by the `G2` public key of the secret.
+#[test]
+fn test_eip2335_vectors() {
+    let expected = SecretKey::from_hex_str(SECRET_HEX).unwrap();
+
+    for path in ["tests/keystore_scrypt.json", "tests/keystore_pbkdf2.json"] {
+        let keystore = Keystore::read_file(path).unwrap();
+        assert_eq!(keystore.public_key().unwrap(), expected.get_public_key());
+        assert_eq!(keystore.decrypt(PASSWORD).unwrap(), expected);
+        assert_eq!(
+            keystore.decrypt("testpassword"),
+            Err(BlsError::InvalidPassword)
+        );
+    }
+}
+
+#[test]
+fn test_keystore_round_trip() {
+    let sk = random_secret_key();
+
+    for kdf in [LIGHT_KDF, KeystoreKdf::Scrypt { n: 16, r: 8, p: 1 }] {
+        let keystore = Keystore::encrypt(&sk, "p\u{7f}assword", kdf).unwrap();
+        assert_eq!(keystore.version, KEYSTORE_VERSION);
+        assert_eq!(keystore.public_key().unwrap(), sk.get_public_key());
+
+        let json = keystore.to_json().unwrap();
+        let parsed = Keystore::from_json(&json).unwrap();
+        assert_eq!(parsed, keystore);
+        // control codes are stripped from passwords
+        assert_eq!(parsed.decrypt("password").unwrap(), sk);
+        assert_eq!(parsed.decrypt("passwords"), Err(BlsError::InvalidPassword));
+    }
+
+    let a = Keystore::encrypt(&sk, "password", LIGHT_KDF).unwrap();
+    let b = Keystore::encrypt(&sk, "password", LIGHT_KDF).unwrap();
+    assert_ne!(a.uuid, b.uuid);
+    assert_ne!(a.crypto.cipher.message, b.crypto.cipher.message);
+}
+
+#[test]
+fn test_keystore_file_round_trip() {
+    let keystore = Keystore::encrypt(&random_secret_key(), "password", LIGHT_KDF).unwrap();
+
+    let path = std::env::temp_dir().join(format!("keystore-{}.json", std::process::id()));
+    fs::write(&path, "previous contents").unwrap();
+    keystore.write_file(&path).unwrap();
+    let read = Keystore::read_file(&path);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(read.unwrap(), keystore);
+}
+
+#[test]
+fn test_keystore_parameter_validation() {
+    let sk = random_secret_key();
+
+    for kdf in [
+        KeystoreKdf::Pbkdf2 { c: 0 },
+        KeystoreKdf::Scrypt { n: 15, r: 8, p: 1 },
+        KeystoreKdf::Scrypt { n: 1, r: 8, p: 1 },
+        KeystoreKdf::Scrypt { n: 16, r: 0, p: 1 },
+        KeystoreKdf::Pbkdf2 { c: (1 << 24) + 1 },
+        KeystoreKdf::Scrypt {
+            n: 1 << 21,
+            r: 8,
+            p: 1,
+        },
+        KeystoreKdf::Scrypt { n: 16, r: 33, p: 1 },
+        KeystoreKdf::Scrypt { n: 16, r: 8, p: 17 },
+        KeystoreKdf::Scrypt {
+            n: 16,
+            r: u32::MAX,
+            p: u32::MAX,
+        },
+    ] {
+        assert_eq!(
+            Keystore::encrypt(&sk, "password", kdf),
+            Err(BlsError::InvalidKeystore)
+        );
+    }
+
+    let keystore = Keystore::encrypt(&sk, "password", LIGHT_KDF).unwrap();
+    let mut tampered = Vec::new();
+
+    let mut k = keystore.clone();
+    k.version = 3;
+    tampered.push(k);
+
+    let mut k = keystore.clone();
+    k.uuid = "not a uuid".to_string();
+    tampered.push(k);
+
+    let mut k = keystore.clone();
+    k.crypto.kdf.params.insert("dklen".into(), 16.into());
+    tampered.push(k);
+
+    let mut k = keystore.clone();
+    k.crypto
+        .kdf
+        .params
+        .insert("prf".into(), "hmac-sha512".into());
+    tampered.push(k);
+
+    let mut k = keystore.clone();
+    k.crypto.kdf.params.insert("extra".into(), 1.into());
+    tampered.push(k);
+
+    let mut k = keystore.clone();
+    k.crypto.kdf.params.insert("c".into(), (1u32 << 25).into());
+    tampered.push(k);
+
+    let mut k = keystore.clone();
+    k.crypto.kdf.function = "argon2".into();
+    tampered.push(k);
+
+    let mut k = keystore.clone();
+    k.crypto.checksum.function = "sha512".into();
+    tampered.push(k);
+
+    let mut k = keystore.clone();
+    k.crypto.cipher.function = "aes-256-ctr".into();
+    tampered.push(k);
+
+    let mut k = keystore.clone();
+    k.crypto.cipher.params.insert("iv".into(), "00".into());
+    tampered.push(k);
+
+    let mut k = keystore.clone();
+    k.crypto.cipher.message.push_str("00");
+    tampered.push(k);
+
+    for k in tampered {
+        assert_eq!(k.decrypt("password"), Err(BlsError::InvalidKeystore));
+    }
+
+    let other = random_secret_key();
+    let mut k = keystore.clone();
+    k.pubkey = hex::encode(other.get_public_key().serialize().unwrap());
+    assert_eq!(k.decrypt("password"), Err(BlsError::PublicKeyMismatch));
+
+    assert_eq!(
+        Keystore::from_json("{\"version\": 4}"),
+        Err(BlsError::InvalidKeystore)
+    );
+}
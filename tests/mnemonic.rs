@@ -0,0 +1,74 @@
+mod common;
+
+use multiversx_bls::*;
+
+use common::random_secret_key;
+
+const ABANDON_ABOUT: &str =
+    "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+#[test]
+fn test_mnemonic_seed_matches_eip2333() {
+    // BIP-39 reference vector, whose seed is the first EIP-2333 test seed
+    let mnemonic = parse_mnemonic(ABANDON_ABOUT).unwrap();
+    let sk = SecretKey::from_mnemonic(&mnemonic, "TREZOR").unwrap();
+    let expected =
+        SecretKey::from_hex_str("0d7359d57963ab8fbbde1852dcf553fedbc31f464d80ee7d40ae683122b45070")
+            .unwrap();
+    assert_eq!(sk, expected);
+
+    assert_ne!(SecretKey::from_mnemonic(&mnemonic, "").unwrap(), expected);
+    assert_eq!(
+        SecretKey::from_mnemonic_and_path(&mnemonic, "TREZOR", "m/0").unwrap(),
+        expected.derive_child(0).unwrap()
+    );
+}
+
+#[test]
+fn test_mnemonic_checksum() {
+    let bad_checksum = ABANDON_ABOUT.replace("about", "abandon");
+    assert_eq!(
+        parse_mnemonic(&bad_checksum),
+        Err(BlsError::InvalidMnemonic)
+    );
+    let unknown_word = ABANDON_ABOUT.replace("about", "aboot");
+    assert_eq!(
+        parse_mnemonic(&unknown_word),
+        Err(BlsError::InvalidMnemonic)
+    );
+    assert_eq!(generate_mnemonic(13).err(), Some(BlsError::InvalidMnemonic));
+}
+
+#[test]
+fn test_generated_mnemonic_round_trip() {
+    let mnemonic = generate_mnemonic(24).unwrap();
+    let parsed = parse_mnemonic(&mnemonic.to_string()).unwrap();
+
+    assert_eq!(
+        SecretKey::from_mnemonic(&mnemonic, "pass").unwrap(),
+        SecretKey::from_mnemonic(&parsed, "pass").unwrap()
+    );
+}
+
+#[test]
+fn test_secret_key_mnemonic_round_trip() {
+    let sk = random_secret_key();
+
+    let mnemonic = sk.to_entropy_mnemonic().unwrap();
+    assert_eq!(mnemonic.word_count(), SECRET_KEY_MNEMONIC_WORDS);
+
+    let parsed = parse_mnemonic(&mnemonic.to_string()).unwrap();
+    assert_eq!(SecretKey::from_entropy_mnemonic(&parsed).unwrap(), sk);
+
+    // 2^256 - 1 is not a valid scalar
+    let out_of_range = Mnemonic::from_entropy(&[0xff; 32]).unwrap();
+    assert_eq!(
+        SecretKey::from_entropy_mnemonic(&out_of_range),
+        Err(BlsError::InvalidMnemonic)
+    );
+    let too_short = parse_mnemonic(ABANDON_ABOUT).unwrap();
+    assert_eq!(
+        SecretKey::from_entropy_mnemonic(&too_short),
+        Err(BlsError::InvalidMnemonic)
+    );
+}
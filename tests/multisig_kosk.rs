@@ -0,0 +1,189 @@
+mod common;
+
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+use multiversx_bls::multisig::kosk;
+use multiversx_bls::*;
+
+use common::random_secret_keys;
+
+struct Vector {
+    pubs: Vec<Vec<u8>>,
+    msg: Vec<u8>,
+    sig: Vec<u8>,
+    out: bool,
+}
+
+// `pub`, `msg` and `sig` are hex, `out` is the expected verification result
+fn read_vectors(path: &str) -> Vec<Vector> {
+    let file = BufReader::new(File::open(path).unwrap());
+    let mut vectors = Vec::new();
+    let (mut pubs, mut msg, mut sig) = (Vec::new(), Vec::new(), Vec::new());
+
+    for l in file.lines() {
+        let line = l.unwrap();
+        let elements: Vec<&str> = line.split_whitespace().collect();
+        match elements[0] {
+            "pub" => pubs.push(hex::decode(elements[1]).unwrap()),
+            "msg" => msg = hex::decode(elements[1]).unwrap(),
+            "sig" => sig = hex::decode(elements[1]).unwrap(),
+            "out" => {
+                vectors.push(Vector {
+                    pubs: std::mem::take(&mut pubs),
+                    msg: msg.clone(),
+                    sig: sig.clone(),
+                    out: elements[1] == "true",
+                });
+            }
+            _ => (),
+        }
+    }
+
+    vectors
+}
+
+#[test]
+fn test_create_signature_share() {
+    let file = BufReader::new(File::open("tests/sign.txt").unwrap());
+    let mut sk = SecretKey::default();
+    let mut msg = String::new();
+
+    for l in file.lines() {
+        let line = l.unwrap();
+        let v: Vec<&str> = line.split(' ').collect();
+        match v[0] {
+            "sec" => sk = SecretKey::from_serialized(&hex::decode(v[1]).unwrap()).unwrap(),
+            "msg" => msg = v[1].to_string(),
+            "out" => {
+                let share = kosk::create_signature_share(&sk, msg.as_bytes()).unwrap();
+                assert_eq!(hex::encode(&share), v[1]);
+
+                let pk = sk.get_public_key().serialize().unwrap();
+                kosk::verify_signature_share(&pk, msg.as_bytes(), &share).unwrap();
+            }
+            _ => (),
+        }
+    }
+
+    assert_eq!(
+        kosk::create_signature_share(&sk, &[]),
+        Err(BlsError::InvalidData)
+    );
+    assert_eq!(
+        kosk::create_signature_share(&SecretKey::default(), b"abc"),
+        Err(BlsError::InvalidData)
+    );
+}
+
+#[test]
+fn test_verify_signature_share() {
+    for Vector {
+        pubs,
+        msg,
+        sig,
+        out,
+    } in read_vectors("tests/multisig_kosk_share.txt")
+    {
+        let result = kosk::verify_signature_share(&pubs[0], &msg, &sig);
+        if out {
+            result.unwrap();
+        } else {
+            assert_eq!(result, Err(BlsError::InvalidSignature));
+        }
+
+        assert_eq!(
+            kosk::verify_signature_share(&pubs[0], &[], &sig),
+            Err(BlsError::InvalidData)
+        );
+        assert_eq!(
+            kosk::verify_signature_share(&pubs[0], &msg, &[]),
+            Err(BlsError::InvalidData)
+        );
+        assert_eq!(
+            kosk::verify_signature_share(&[], &msg, &sig),
+            Err(BlsError::InvalidData)
+        );
+    }
+}
+
+#[test]
+fn test_aggregate_signatures() {
+    let file = BufReader::new(File::open("tests/aggregate.txt").unwrap());
+    let mut sigs = Vec::new();
+
+    for l in file.lines() {
+        let line = l.unwrap();
+        let elements: Vec<&str> = line.split_whitespace().collect();
+        match elements[0] {
+            "sig" => sigs.push(hex::decode(elements[1]).unwrap()),
+            "out" => {
+                let aggregated = kosk::aggregate_signatures(&sigs).unwrap();
+                assert_eq!(hex::encode(aggregated), elements[1]);
+                sigs.clear();
+            }
+            _ => (),
+        }
+    }
+
+    assert_eq!(
+        kosk::aggregate_signatures::<Vec<u8>>(&[]),
+        Err(BlsError::InvalidData)
+    );
+    assert_eq!(
+        kosk::aggregate_signatures(&[[0u8; 48]]),
+        Err(BlsError::InvalidData)
+    );
+}
+
+#[test]
+fn test_verify_aggregated_signature() {
+    for Vector {
+        pubs,
+        msg,
+        sig,
+        out,
+    } in read_vectors("tests/multisig_kosk_aggregate.txt")
+    {
+        let result = kosk::verify_aggregated_signature(&pubs, &msg, &sig);
+        if out {
+            result.unwrap();
+        } else {
+            assert_eq!(result, Err(BlsError::InvalidSignature));
+        }
+
+        assert_eq!(
+            kosk::verify_aggregated_signature::<Vec<u8>>(&[], &msg, &sig),
+            Err(BlsError::InvalidData)
+        );
+        assert_eq!(
+            kosk::verify_aggregated_signature(&pubs, &[], &sig),
+            Err(BlsError::InvalidData)
+        );
+        assert_eq!(
+            kosk::verify_aggregated_signature(&pubs, &msg, &sig[..47]),
+            Err(BlsError::InvalidData)
+        );
+    }
+}
+
+#[test]
+fn test_kosk_round_trip() {
+    let msg = b"header hash";
+    let sks = random_secret_keys(4);
+    let pubs: Vec<Vec<u8>> = sks
+        .iter()
+        .map(|sk| sk.get_public_key().serialize().unwrap())
+        .collect();
+    let shares: Vec<Vec<u8>> = sks
+        .iter()
+        .map(|sk| kosk::create_signature_share(sk, msg).unwrap())
+        .collect();
+
+    let aggregated = kosk::aggregate_signatures(&shares).unwrap();
+    kosk::verify_aggregated_signature(&pubs, msg, &aggregated).unwrap();
+    assert_eq!(
+        kosk::verify_aggregated_signature(&pubs[1..], msg, &aggregated),
+        Err(BlsError::InvalidSignature)
+    );
+}
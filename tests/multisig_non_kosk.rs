@@ -0,0 +1,156 @@
+mod common;
+
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+use multiversx_bls::multisig::{kosk, non_kosk};
+use multiversx_bls::*;
+
+use common::{random_secret_keys, MINUS_ONE_HEX};
+
+struct Vector {
+    pubs: Vec<G2>,
+    msg: Vec<u8>,
+    apk: Option<G2>,
+    sig: G1,
+    out: bool,
+}
+
+// `pub`, `msg`, `apk` and `sig` are hex, `out` is the expected verification result
+//
+// The vectors were computed without this crate, from signature shares created as in `kosk`:
+// the big-endian coefficients with the blake2b of Python's hashlib and the weighted sums `apk`
+// and `sig` with the zkcrypto bls12_381 crate. They check the scheme as documented, not its
+// byte compatibility with mx-chain-crypto-go, which needs vectors of the Go implementation.
+fn read_vectors(path: &str) -> Vec<Vector> {
+    let file = BufReader::new(File::open(path).unwrap());
+    let mut vectors = Vec::new();
+    let (mut pubs, mut msg, mut apk, mut sig) = (Vec::new(), Vec::new(), None, G1::default());
+
+    for l in file.lines() {
+        let line = l.unwrap();
+        let elements: Vec<&str> = line.split_whitespace().collect();
+        let buf = hex::decode(elements[1]).unwrap_or_default();
+        match elements[0] {
+            "pub" => pubs.push(G2::from_serialized(&buf).unwrap()),
+            "msg" => msg = buf,
+            "apk" => apk = Some(G2::from_serialized(&buf).unwrap()),
+            "sig" => sig = G1::from_serialized(&buf).unwrap(),
+            "out" => {
+                vectors.push(Vector {
+                    pubs: std::mem::take(&mut pubs),
+                    msg: msg.clone(),
+                    apk: apk.take(),
+                    sig,
+                    out: elements[1] == "true",
+                });
+            }
+            _ => (),
+        }
+    }
+
+    vectors
+}
+
+#[test]
+fn test_non_kosk_vectors() {
+    let vectors = read_vectors("tests/multisig_non_kosk.txt");
+    assert_eq!(vectors.len(), 10);
+
+    for v in vectors {
+        if let Some(apk) = v.apk {
+            assert_eq!(non_kosk::aggregate_public_keys(&v.pubs).unwrap(), apk);
+        }
+        assert_eq!(
+            non_kosk::verify_aggregated_signature(&v.pubs, &v.msg, &v.sig).is_ok(),
+            v.out
+        );
+    }
+}
+
+#[test]
+fn test_coefficients() {
+    let pks: Vec<G2> = random_secret_keys(3)
+        .iter()
+        .map(|sk| sk.get_public_key())
+        .collect();
+    let coefficients = non_kosk::coefficients(&pks).unwrap();
+
+    // 128-bit coefficients
+    for coefficient in &coefficients {
+        assert!(coefficient.serialize().unwrap()[16..]
+            .iter()
+            .all(|b| *b == 0));
+    }
+
+    // coefficients depend on the signing order
+    let reversed: Vec<G2> = pks.iter().rev().copied().collect();
+    assert_ne!(
+        non_kosk::coefficients(&reversed).unwrap()[2],
+        coefficients[0]
+    );
+    assert_eq!(non_kosk::coefficients(&[]), Err(BlsError::InvalidData));
+}
+
+#[test]
+fn test_non_kosk_round_trip() {
+    let msg = b"header hash";
+    let sks = random_secret_keys(4);
+    let pks: Vec<G2> = sks.iter().map(|sk| sk.get_public_key()).collect();
+    let shares: Vec<G1> = sks
+        .iter()
+        .map(|sk| {
+            let share = non_kosk::create_signature_share(sk, msg).unwrap();
+            G1::from_serialized(&share).unwrap()
+        })
+        .collect();
+
+    let aggregated = non_kosk::aggregate_signatures(&pks, &shares).unwrap();
+    non_kosk::verify_aggregated_signature(&pks, msg, &aggregated).unwrap();
+    assert!(aggregated.verify(non_kosk::aggregate_public_keys(&pks).unwrap(), msg));
+
+    // the shares are not simply added
+    let mut sum = G1::default();
+    sum.aggregate(&shares);
+    assert_ne!(sum, aggregated);
+
+    assert_eq!(
+        non_kosk::verify_aggregated_signature(&pks[1..], msg, &aggregated),
+        Err(BlsError::InvalidSignature)
+    );
+    assert_eq!(
+        non_kosk::verify_aggregated_signature(&pks, b"other", &aggregated),
+        Err(BlsError::InvalidSignature)
+    );
+    assert_eq!(
+        non_kosk::verify_aggregated_signature(&pks, &[], &aggregated),
+        Err(BlsError::InvalidData)
+    );
+    assert_eq!(
+        non_kosk::aggregate_signatures(&pks[1..], &shares),
+        Err(BlsError::BadSize)
+    );
+}
+
+#[test]
+fn test_rogue_key_attack() {
+    let msg = b"header hash";
+    let victim = random_secret_keys(1)[0].get_public_key();
+    let attacker = random_secret_keys(1)[0];
+
+    // rogue key: attacker * G - victim
+    let mut rogue = victim;
+    rogue.mul_assign(&SecretKey::from_hex_str(MINUS_ONE_HEX).unwrap());
+    rogue.add_assign(attacker.get_public_key());
+    let forged = attacker.sign(msg);
+
+    // plain aggregation accepts the forgery, which KOSK prevents with proofs of possession
+    let pks = [victim, rogue];
+    let serialized: Vec<Vec<u8>> = pks.iter().map(|pk| pk.serialize().unwrap()).collect();
+    kosk::verify_aggregated_signature(&serialized, msg, &forged.serialize().unwrap()).unwrap();
+
+    assert_eq!(
+        non_kosk::verify_aggregated_signature(&pks, msg, &forged),
+        Err(BlsError::InvalidSignature)
+    );
+}
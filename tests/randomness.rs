@@ -0,0 +1,89 @@
+mod common;
+
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+use multiversx_bls::randomness;
+use multiversx_bls::*;
+
+use common::random_secret_keys;
+
+#[test]
+fn test_next_rand_seed() {
+    let file = BufReader::new(File::open("tests/sign.txt").unwrap());
+    let mut sk = SecretKey::default();
+    let mut prev_rand_seed = Vec::new();
+
+    for l in file.lines() {
+        let line = l.unwrap();
+        let v: Vec<&str> = line.split(' ').collect();
+        match v[0] {
+            "sec" => sk = SecretKey::from_serialized(&hex::decode(v[1]).unwrap()).unwrap(),
+            "msg" => prev_rand_seed = v[1].as_bytes().to_vec(),
+            "out" => {
+                let rand_seed = randomness::next_rand_seed(&sk, &prev_rand_seed).unwrap();
+                assert_eq!(hex::encode(&rand_seed), v[1]);
+                randomness::verify_rand_seed(&sk.get_public_key(), &prev_rand_seed, &rand_seed)
+                    .unwrap();
+            }
+            _ => (),
+        }
+    }
+
+    assert_eq!(
+        randomness::next_rand_seed(&sk, &[]),
+        Err(BlsError::InvalidData)
+    );
+    assert_eq!(
+        randomness::next_rand_seed(&SecretKey::default(), b"seed"),
+        Err(BlsError::InvalidData)
+    );
+}
+
+#[test]
+fn test_verify_rand_seed_chain() {
+    let leaders = random_secret_keys(5);
+    let leader_keys: Vec<G2> = leaders.iter().map(|sk| sk.get_public_key()).collect();
+    let start_rand_seed = b"genesis random seed".to_vec();
+
+    let mut rand_seeds: Vec<Vec<u8>> = Vec::new();
+    for leader in &leaders {
+        let prev_rand_seed = rand_seeds.last().unwrap_or(&start_rand_seed);
+        rand_seeds.push(randomness::next_rand_seed(leader, prev_rand_seed).unwrap());
+    }
+
+    assert_eq!(
+        randomness::verify_rand_seed_chain(&start_rand_seed, &leader_keys, &rand_seeds).unwrap(),
+        rand_seeds[4]
+    );
+    assert_eq!(
+        randomness::verify_rand_seed_chain::<Vec<u8>>(&start_rand_seed, &[], &[]).unwrap(),
+        start_rand_seed
+    );
+
+    // seed proposed by another leader
+    let mut swapped = leader_keys.clone();
+    swapped.swap(1, 2);
+    assert_eq!(
+        randomness::verify_rand_seed_chain(&start_rand_seed, &swapped, &rand_seeds),
+        Err(BlsError::InvalidSignature)
+    );
+
+    // seed not chained to the previous one
+    let mut forged = rand_seeds.clone();
+    forged[3] = randomness::next_rand_seed(&leaders[3], b"chosen seed").unwrap();
+    assert_eq!(
+        randomness::verify_rand_seed_chain(&start_rand_seed, &leader_keys, &forged),
+        Err(BlsError::InvalidSignature)
+    );
+
+    forged[3] = vec![0; 48];
+    assert_eq!(
+        randomness::verify_rand_seed_chain(&start_rand_seed, &leader_keys, &forged),
+        Err(BlsError::InvalidData)
+    );
+    assert_eq!(
+        randomness::verify_rand_seed_chain(&start_rand_seed, &leader_keys[1..], &rand_seeds),
+        Err(BlsError::BadSize)
+    );
+}
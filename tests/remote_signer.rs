@@ -0,0 +1,260 @@
+mod common;
+
+use std::fs;
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::time::{Duration, Instant};
+
+use multiversx_bls::*;
+
+use common::random_secret_key;
+
+fn start_server() -> (SocketAddr, KeyRing, RemoteSignerToken) {
+    let token = RemoteSignerToken::generate().unwrap();
+    let server = RemoteSignerServer::bind(
+        "127.0.0.1:0",
+        KeyRing::load("tests/allValidatorsKeys.pem").unwrap(),
+        token.clone(),
+    )
+    .unwrap();
+    let addr = server.local_addr().unwrap();
+    std::thread::spawn(move || server.serve());
+
+    (
+        addr,
+        KeyRing::load("tests/allValidatorsKeys.pem").unwrap(),
+        token,
+    )
+}
+
+/// return the response of the server at `addr` to the raw `request`
+fn send(addr: SocketAddr, request: &str) -> String {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    stream.write_all(request.as_bytes()).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+
+    response
+}
+
+#[test]
+fn test_remote_sign() {
+    let (addr, key_ring, token) = start_server();
+
+    let public_keys = RemoteSigner::public_keys(addr, &token).unwrap();
+    assert_eq!(public_keys, key_ring.public_keys());
+
+    for public_key in public_keys {
+        let signer = RemoteSigner::new(addr, public_key, token.clone());
+        assert_eq!(signer.public_key(), public_key);
+        for msg in [&b"header hash"[..], &[], &[0xff; 4096]] {
+            assert_eq!(
+                signer.sign(msg).unwrap(),
+                key_ring.sign(&public_key, msg).unwrap()
+            );
+        }
+
+        let msgs = [&b"header hash"[..], &[], b"proof"];
+        let signatures = signer.sign_batch(&msgs).unwrap();
+        assert_eq!(signatures.len(), msgs.len());
+        for (signature, msg) in signatures.iter().zip(msgs) {
+            assert_eq!(*signature, key_ring.sign(&public_key, msg).unwrap());
+        }
+    }
+
+    let sk = random_secret_key();
+    let unknown = RemoteSigner::new(addr, sk.get_public_key(), token);
+    assert_eq!(
+        unknown.sign(b"header hash"),
+        Err(BlsError::UnknownPublicKey)
+    );
+    assert_eq!(
+        unknown.sign_batch(&[b"header hash"]),
+        Err(BlsError::UnknownPublicKey)
+    );
+}
+
+#[test]
+fn test_remote_signer_errors() {
+    let (addr, _, token) = start_server();
+    let headers = format!(
+        "Host: {addr}\r\nAuthorization: Bearer {}",
+        token_hex(&token)
+    );
+
+    let response = send(
+        addr,
+        &format!(
+            "POST /sign HTTP/1.1\r\n{headers}\r\nContent-Type: application/json\r\nContent-Length: 9\r\n\r\nnot json!"
+        ),
+    );
+    assert!(response.starts_with("HTTP/1.1 400 Bad Request\r\n"));
+
+    let response = send(addr, &format!("GET /secret HTTP/1.1\r\n{headers}\r\n\r\n"));
+    assert!(response.starts_with("HTTP/1.1 404 Not Found\r\n"));
+
+    // the server keeps serving after bad requests
+    assert_eq!(RemoteSigner::public_keys(addr, &token).unwrap().len(), 3);
+
+    let closed = {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        listener.local_addr().unwrap()
+    };
+    assert!(matches!(
+        RemoteSigner::public_keys(closed, &token),
+        Err(BlsError::IoError(_))
+    ));
+}
+
+#[test]
+fn test_idle_connections() {
+    let (addr, key_ring, token) = start_server();
+    let public_key = key_ring.public_keys()[0];
+
+    // connections that never send their request do not hold back the others
+    let idle: Vec<TcpStream> = (0..4).map(|_| TcpStream::connect(addr).unwrap()).collect();
+    let start = Instant::now();
+    assert_eq!(
+        RemoteSigner::new(addr, public_key, token)
+            .sign(b"header hash")
+            .unwrap(),
+        key_ring.sign(&public_key, b"header hash").unwrap()
+    );
+    assert!(start.elapsed() < Duration::from_secs(1));
+
+    // and are closed by the server
+    let mut response = Vec::new();
+    let mut stream = &idle[0];
+    stream
+        .set_read_timeout(Some(Duration::from_secs(10)))
+        .unwrap();
+    stream.read_to_end(&mut response).unwrap();
+    assert!(response.starts_with(b"HTTP/1.1 400 Bad Request\r\n"));
+}
+
+#[test]
+fn test_bind_address() {
+    for addr in ["0.0.0.0:0", "[::]:0"] {
+        assert_eq!(
+            RemoteSignerServer::bind(addr, KeyRing::new(), RemoteSignerToken::generate().unwrap())
+                .map(|_| ()),
+            Err(BlsError::InvalidAddress)
+        );
+    }
+
+    let server = RemoteSignerServer::bind_remote(
+        "0.0.0.0:0",
+        KeyRing::new(),
+        RemoteSignerToken::generate().unwrap(),
+    )
+    .unwrap();
+    assert!(server.local_addr().unwrap().ip().is_unspecified());
+}
+
+/// return the hex token of `token`, as written to its file
+fn token_hex(token: &RemoteSignerToken) -> String {
+    let path = std::env::temp_dir().join(format!(
+        "remote-signer-token-{}-{:?}",
+        std::process::id(),
+        std::thread::current().id()
+    ));
+    token.write_file(&path).unwrap();
+    let contents = fs::read_to_string(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    contents.trim_end().to_string()
+}
+
+#[test]
+fn test_authentication() {
+    let (addr, key_ring, token) = start_server();
+    let public_key = key_ring.public_keys()[0];
+
+    let other = RemoteSignerToken::generate().unwrap();
+    assert_ne!(other, token);
+    assert_eq!(
+        RemoteSigner::public_keys(addr, &other),
+        Err(BlsError::Unauthorized)
+    );
+    assert_eq!(
+        RemoteSigner::new(addr, public_key, other).sign(b"header hash"),
+        Err(BlsError::Unauthorized)
+    );
+
+    let body = format!(
+        r#"{{"publicKey":"{}","message":"00"}}"#,
+        hex::encode(public_key.serialize().unwrap())
+    );
+    let authorization = format!("Authorization: Bearer {}", token_hex(&token));
+    let sign = |headers: &str| {
+        send(
+            addr,
+            &format!(
+                "POST /sign HTTP/1.1\r\n{headers}\r\nContent-Length: {}\r\n\r\n{body}",
+                body.len()
+            ),
+        )
+    };
+    assert!(sign(&format!(
+        "Host: {addr}\r\n{authorization}\r\nContent-Type: application/json"
+    ))
+    .starts_with("HTTP/1.1 200 OK\r\n"));
+
+    // without the token
+    assert!(
+        sign(&format!("Host: {addr}\r\nContent-Type: application/json"))
+            .starts_with("HTTP/1.1 401 Unauthorized\r\n")
+    );
+    // from a web page whose host name resolves to the server
+    assert!(sign(&format!(
+        "Host: rebinding.example:{}\r\n{authorization}\r\nContent-Type: application/json",
+        addr.port()
+    ))
+    .starts_with("HTTP/1.1 403 Forbidden\r\n"));
+    assert!(sign(&format!(
+        "{authorization}\r\nContent-Type: application/json"
+    ))
+    .starts_with("HTTP/1.1 403 Forbidden\r\n"));
+    // without a JSON body
+    for content_type in ["", "\r\nContent-Type: text/plain"] {
+        assert!(
+            sign(&format!("Host: {addr}\r\n{authorization}{content_type}"))
+                .starts_with("HTTP/1.1 415 Unsupported Media Type\r\n")
+        );
+    }
+}
+
+#[test]
+fn test_token_file() {
+    let token = RemoteSignerToken::generate().unwrap();
+    assert_eq!(format!("{token:?}"), "RemoteSignerToken(..)");
+
+    let path = std::env::temp_dir().join(format!("remote-signer-{}.token", std::process::id()));
+    token.write_file(&path).unwrap();
+    let read = RemoteSignerToken::read_file(&path);
+    let contents = fs::read_to_string(&path).unwrap();
+    #[cfg(unix)]
+    let readable_by_others = {
+        use std::os::unix::fs::PermissionsExt;
+        assert_eq!(
+            fs::metadata(&path).unwrap().permissions().mode() & 0o777,
+            0o600
+        );
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+        let read = RemoteSignerToken::read_file(&path);
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o600)).unwrap();
+        read
+    };
+    fs::write(&path, "short token\n").unwrap();
+    let invalid = RemoteSignerToken::read_file(&path);
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(read.unwrap(), token);
+    assert_eq!(contents.len(), 2 * REMOTE_SIGNER_TOKEN_SIZE + 1);
+    #[cfg(unix)]
+    assert_eq!(
+        readable_by_others,
+        Err(BlsError::IoError(std::io::ErrorKind::PermissionDenied))
+    );
+    assert_eq!(invalid, Err(BlsError::InvalidData));
+}
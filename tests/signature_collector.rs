@@ -0,0 +1,173 @@
+mod common;
+
+use multiversx_bls::*;
+
+use common::random_secret_keys;
+
+const MSG: &[u8] = b"header hash";
+
+fn collector(sks: &[SecretKey]) -> SignatureCollector {
+    let set = ValidatorSet::new(sks.iter().map(|sk| sk.get_public_key()).collect()).unwrap();
+    SignatureCollector::new(set, MSG)
+}
+
+#[test]
+fn test_collect_until_threshold() {
+    let sks = random_secret_keys(7);
+    let mut collector = collector(&sks);
+    assert_eq!(collector.threshold(), 5);
+
+    for i in [6, 0, 3, 1] {
+        collector.add(i, sks[i].sign(MSG)).unwrap();
+    }
+    assert!(!collector.is_threshold_reached());
+    assert_eq!(collector.aggregate(), Err(BlsError::ThresholdNotReached));
+
+    collector.add(4, sks[4].sign(MSG)).unwrap();
+    assert!(collector.is_threshold_reached());
+    assert_eq!(collector.signers(), [0, 1, 3, 4, 6]);
+
+    let (signature, bitmap) = collector.aggregate().unwrap();
+    assert_eq!(bitmap, [0b0101_1011]);
+    collector
+        .validator_set()
+        .verify_aggregated(&bitmap, &signature, MSG)
+        .unwrap();
+}
+
+#[test]
+fn test_rejected_shares() {
+    let sks = random_secret_keys(4);
+    let mut collector = collector(&sks);
+
+    collector.add(0, sks[0].sign(MSG)).unwrap();
+    assert_eq!(
+        collector.add(0, sks[0].sign(MSG)),
+        Err(BlsError::DuplicateSigner)
+    );
+    assert_eq!(
+        collector.add(4, sks[0].sign(MSG)),
+        Err(BlsError::UnknownSigner)
+    );
+    assert_eq!(
+        collector.add(1, sks[2].sign(MSG)),
+        Err(BlsError::InvalidSignature)
+    );
+    assert_eq!(
+        collector.add(1, sks[1].sign(b"other")),
+        Err(BlsError::InvalidSignature)
+    );
+    assert_eq!(collector.add(1, G1::default()), Err(BlsError::InvalidData));
+    assert_eq!(collector.signers(), [0]);
+    assert_eq!(collector.signed_weight(), 1);
+}
+
+#[test]
+fn test_weighted_threshold() {
+    let sks = random_secret_keys(4);
+    let mut collector = collector(&sks).with_weights(vec![10, 50, 20, 20]).unwrap();
+    assert_eq!(collector.total_weight(), 100);
+    assert_eq!(collector.threshold(), 67);
+
+    collector.add(0, sks[0].sign(MSG)).unwrap();
+    collector.add(1, sks[1].sign(MSG)).unwrap();
+    assert_eq!(collector.signed_weight(), 60);
+    assert!(!collector.is_threshold_reached());
+    collector.add(3, sks[3].sign(MSG)).unwrap();
+    assert!(collector.is_threshold_reached());
+
+    let collector = collector.with_threshold(90).unwrap();
+    assert!(!collector.is_threshold_reached());
+    assert_eq!(collector.threshold(), 90);
+
+    // thresholds that can never or always be reached
+    for threshold in [0, 101] {
+        assert_eq!(
+            self::collector(&sks)
+                .with_weights(vec![10, 50, 20, 20])
+                .unwrap()
+                .with_threshold(threshold)
+                .err(),
+            Some(BlsError::InvalidData)
+        );
+    }
+    assert_eq!(
+        self::collector(&sks)
+            .with_threshold(4)
+            .unwrap()
+            .with_weights(vec![1, 1, 0, 1])
+            .err(),
+        Some(BlsError::InvalidData)
+    );
+
+    assert_eq!(
+        self::collector(&sks).with_weights(vec![1, 2]).err(),
+        Some(BlsError::BadSize)
+    );
+    assert_eq!(
+        self::collector(&sks).with_weights(vec![0; 4]).err(),
+        Some(BlsError::InvalidData)
+    );
+    assert_eq!(
+        self::collector(&sks)
+            .with_weights(vec![u64::MAX, 1, 0, 0])
+            .err(),
+        Some(BlsError::InvalidData)
+    );
+}
+
+#[test]
+fn test_lazy_verification() {
+    let sks = random_secret_keys(6);
+    let mut collector = collector(&sks).with_verification(ShareVerification::Lazy);
+
+    for (i, sk) in sks.iter().enumerate().take(5) {
+        collector.add(i, sk.sign(MSG)).unwrap();
+    }
+    // accepted without verification
+    collector.add(5, sks[0].sign(MSG)).unwrap();
+    assert_eq!(collector.invalid_shares().unwrap(), [5]);
+
+    let (signature, bitmap) = collector.aggregate().unwrap();
+    assert_eq!(collector.signers(), [0, 1, 2, 3, 4]);
+    collector
+        .validator_set()
+        .verify_aggregated(&bitmap, &signature, MSG)
+        .unwrap();
+
+    let mut collector = self::collector(&sks).with_verification(ShareVerification::Lazy);
+    for i in 0..5 {
+        collector.add(i, sks[5].sign(MSG)).unwrap();
+    }
+    collector.add(5, sks[5].sign(MSG)).unwrap();
+    assert_eq!(collector.aggregate(), Err(BlsError::ThresholdNotReached));
+    assert_eq!(collector.signers(), [5]);
+}
+
+#[test]
+fn test_add_signed() {
+    let sks = random_secret_keys(4);
+    let mut collector = collector(&sks);
+
+    for (index, sk) in sks.iter().enumerate().take(3) {
+        collector.add_signed(index, sk).unwrap();
+    }
+    assert_eq!(
+        collector.add_signed(3, &sks[0]),
+        Err(BlsError::PublicKeyMismatch)
+    );
+    assert_eq!(
+        collector.add_signed(4, &sks[0]),
+        Err(BlsError::UnknownSigner)
+    );
+    assert_eq!(
+        collector.add_signed(0, &sks[0]),
+        Err(BlsError::DuplicateSigner)
+    );
+
+    let (signature, bitmap) = collector.aggregate().unwrap();
+    collector
+        .validator_set()
+        .verify_aggregated(&bitmap, &signature, MSG)
+        .unwrap();
+}
@@ -0,0 +1,229 @@
+mod common;
+
+use multiversx_bls::*;
+
+use common::{random_secret_key, random_secret_keys};
+
+const SLOT: Slot = Slot {
+    epoch: 1207,
+    round: 24_164_301,
+    kind: 2,
+};
+
+#[test]
+fn test_refuse_double_signing() {
+    let sk = random_secret_key();
+    let mut protection = SigningProtection::new();
+
+    let signature = protection.sign(&sk, SLOT, b"header a").unwrap();
+    assert_eq!(signature, sk.sign(b"header a"));
+    assert_eq!(protection.sign(&sk, SLOT, b"header a").unwrap(), signature);
+    assert_eq!(
+        protection.sign(&sk, SLOT, b"header b"),
+        Err(BlsError::DoubleSigning)
+    );
+    assert_eq!(
+        protection.check(&sk.get_public_key(), SLOT, b"header b"),
+        Err(BlsError::DoubleSigning)
+    );
+
+    // other slots and other keys are independent
+    for slot in [
+        Slot {
+            round: 24_164_302,
+            ..SLOT
+        },
+        Slot { kind: 3, ..SLOT },
+        Slot {
+            epoch: 1208,
+            ..SLOT
+        },
+    ] {
+        protection.sign(&sk, slot, b"header b").unwrap();
+    }
+    protection
+        .sign(&random_secret_key(), SLOT, b"header b")
+        .unwrap();
+    assert_eq!(protection.len(), 5);
+}
+
+#[test]
+fn test_export_import() {
+    let sk = random_secret_key();
+    let mut protection = SigningProtection::new();
+    protection.sign(&sk, SLOT, b"header a").unwrap();
+
+    let json = protection.export().unwrap();
+    let interchange: SigningProtectionInterchange = serde_json::from_str(&json).unwrap();
+    assert_eq!(interchange.version, SIGNING_PROTECTION_VERSION);
+    assert_eq!(
+        interchange.records,
+        [SignedSlot {
+            public_key: hex::encode(sk.get_public_key().serialize().unwrap()),
+            epoch: 1207,
+            round: 24_164_301,
+            kind: 2,
+            // SHA-256 of "header a"
+            payload_hash: "39c0745b5bfd24c1262e903f21c260439f5fe33982e092f992d888def6c1a44b"
+                .to_string(),
+        }]
+    );
+
+    // the backup imports the records of the primary before signing
+    let mut backup = SigningProtection::new();
+    backup.import(&json).unwrap();
+    backup.import(&json).unwrap();
+    assert_eq!(backup.len(), 1);
+    assert_eq!(
+        backup.sign(&sk, SLOT, b"header b"),
+        Err(BlsError::DoubleSigning)
+    );
+
+    let mut other = SigningProtection::new();
+    other.sign(&sk, SLOT, b"header b").unwrap();
+    other
+        .sign(&sk, Slot { round: 1, ..SLOT }, b"header b")
+        .unwrap();
+    assert_eq!(other.import(&json), Err(BlsError::DoubleSigning));
+    assert_eq!(other.len(), 2);
+
+    let mut unsupported = interchange.clone();
+    unsupported.version = 2;
+    assert_eq!(
+        other.import(&serde_json::to_string(&unsupported).unwrap()),
+        Err(BlsError::InvalidData)
+    );
+}
+
+#[test]
+fn test_persist_records() {
+    let path = std::env::temp_dir().join(format!("signingProtection-{}.json", std::process::id()));
+    let sk = random_secret_key();
+
+    let mut protection = SigningProtection::open(&path).unwrap();
+    assert!(protection.is_empty());
+    protection.sign(&sk, SLOT, b"header a").unwrap();
+
+    // a restarted signer still refuses the conflicting payload
+    let mut restarted = SigningProtection::open(&path).unwrap();
+    assert_eq!(restarted.len(), 1);
+    assert_eq!(
+        restarted.sign(&sk, SLOT, b"header b"),
+        Err(BlsError::DoubleSigning)
+    );
+    restarted.sign(&sk, SLOT, b"header a").unwrap();
+
+    std::fs::write(&path, "not json").unwrap();
+    assert_eq!(SigningProtection::open(&path), Err(BlsError::InvalidData));
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_prune() {
+    let path = std::env::temp_dir().join(format!(
+        "signingProtectionPrune-{}.json",
+        std::process::id()
+    ));
+    let sk = random_secret_key();
+    let next_epoch = Slot {
+        epoch: 1208,
+        round: 24_178_701,
+        ..SLOT
+    };
+
+    let mut protection = SigningProtection::open(&path).unwrap();
+    protection.sign(&sk, SLOT, b"header a").unwrap();
+    protection.sign(&sk, next_epoch, b"header b").unwrap();
+    assert_eq!(protection.watermark(), None);
+
+    let watermark = Watermark {
+        epoch: 1208,
+        round: 24_178_701,
+    };
+    protection.prune(watermark).unwrap();
+    assert_eq!(protection.len(), 1);
+    assert_eq!(protection.watermark(), Some(watermark));
+
+    // slots below the watermark are refused, even for the payload signed before
+    assert_eq!(
+        protection.sign(&sk, SLOT, b"header a"),
+        Err(BlsError::DoubleSigning)
+    );
+    assert_eq!(
+        protection.sign(&sk, next_epoch, b"header c"),
+        Err(BlsError::DoubleSigning)
+    );
+    protection.sign(&sk, next_epoch, b"header b").unwrap();
+
+    // the watermark never decreases and is persisted
+    protection.prune(Watermark { epoch: 1, round: 1 }).unwrap();
+    let restarted = SigningProtection::open(&path).unwrap();
+    assert_eq!(restarted.watermark(), Some(watermark));
+    assert_eq!(restarted.len(), 1);
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_export_since() {
+    let sk = random_secret_key();
+    let mut protection = SigningProtection::new();
+    for round in 1..=4 {
+        protection
+            .sign(&sk, Slot { round, ..SLOT }, b"header a")
+            .unwrap();
+    }
+
+    let since = Watermark {
+        epoch: SLOT.epoch,
+        round: 3,
+    };
+    let json = protection.export_since(since).unwrap();
+    let interchange: SigningProtectionInterchange = serde_json::from_str(&json).unwrap();
+    assert_eq!(interchange.watermark, Some(since));
+    assert_eq!(interchange.records.len(), 2);
+    assert_eq!(protection.len(), 4);
+
+    // the importer refuses the slots left out of the window
+    let mut backup = SigningProtection::new();
+    backup
+        .sign(&sk, Slot { round: 1, ..SLOT }, b"header b")
+        .unwrap();
+    backup.import(&json).unwrap();
+    assert_eq!(backup.watermark(), Some(since));
+    assert_eq!(backup.len(), 2);
+    for (round, payload) in [(2, &b"header a"[..]), (3, b"header b")] {
+        assert_eq!(
+            backup.sign(&sk, Slot { round, ..SLOT }, payload),
+            Err(BlsError::DoubleSigning)
+        );
+    }
+
+    // interchanges without a watermark are still accepted
+    assert!(!protection.export().unwrap().contains("watermark"));
+}
+
+#[test]
+fn test_consensus_signatures() {
+    let sks = random_secret_keys(3);
+    let validator_set =
+        ValidatorSet::new(sks.iter().map(|sk| sk.get_public_key()).collect()).unwrap();
+    let header_hash = [0x5a; HEADER_HASH_SIZE];
+    let mut protection = SigningProtection::new();
+
+    // the signatures are the ones of the header hash checked by the nodes
+    let signatures: Vec<G1> = sks
+        .iter()
+        .map(|sk| protection.sign(sk, SLOT, &header_hash).unwrap())
+        .collect();
+    for (sk, signature) in sks.iter().zip(&signatures) {
+        assert!(signature.verify(sk.get_public_key(), &header_hash));
+    }
+
+    let mut aggregated = G1::default();
+    aggregated.aggregate(&signatures);
+    let bitmap = validator_set.bitmap(&[0, 1, 2]).unwrap();
+    validator_set
+        .verify_aggregated(&bitmap, &aggregated, &header_hash)
+        .unwrap();
+}
@@ -0,0 +1,74 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+use multiversx_bls::*;
+
+const ALICE: &str = "erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th";
+const ALICE_HEX: &str = "0139472eff6886771a982f3083da5d421f24c29181e63888228dc81ca60d69e1";
+
+#[test]
+fn test_decode_address() {
+    assert_eq!(hex::encode(decode_address(ALICE).unwrap()), ALICE_HEX);
+    assert_eq!(
+        encode_address(&hex::decode(ALICE_HEX).unwrap()).unwrap(),
+        ALICE
+    );
+
+    for invalid in [
+        "",
+        // bad checksum
+        "erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6tt",
+        // other human-readable part
+        "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+        // 20-byte address
+        "erd1w508d6qejxtdg4y5r3zarvary0c5xw7kzdgrht",
+    ] {
+        assert_eq!(decode_address(invalid), Err(BlsError::InvalidAddress));
+    }
+    assert_eq!(encode_address(&[0; 31]), Err(BlsError::InvalidAddress));
+}
+
+#[test]
+fn test_staking_owner_signature() {
+    let file = BufReader::new(File::open("tests/staking.txt").unwrap());
+    let mut sk = SecretKey::default();
+    let mut address = String::new();
+
+    for l in file.lines() {
+        let line = l.unwrap();
+        let v: Vec<&str> = line.split(' ').collect();
+        match v[0] {
+            "sec" => sk = SecretKey::from_serialized(&hex::decode(v[1]).unwrap()).unwrap(),
+            "addr" => address = v[1].to_string(),
+            "out" => {
+                let signature = sk.sign_staking_owner(&address).unwrap();
+                assert_eq!(hex::encode(signature.serialize().unwrap()), v[1]);
+                // the signed message is the decoded address, not its bech32 string
+                assert_eq!(signature, sk.sign(&decode_address(&address).unwrap()));
+
+                let pk = sk.get_public_key();
+                pk.verify_staking_owner(&address, &signature).unwrap();
+                assert_eq!(
+                    pk.verify_staking_owner(ALICE, &sk.sign(address.as_bytes())),
+                    Err(BlsError::InvalidSignature)
+                );
+            }
+            _ => (),
+        }
+    }
+
+    let pk = sk.get_public_key();
+    let signature = sk.sign_staking_owner(ALICE).unwrap();
+    assert_eq!(
+        pk.verify_staking_owner(ALICE, &G1::default()),
+        Err(BlsError::InvalidData)
+    );
+    assert_eq!(
+        G2::default().verify_staking_owner(ALICE, &signature),
+        Err(BlsError::InvalidData)
+    );
+    assert_eq!(
+        pk.verify_staking_owner("erd1invalid", &signature),
+        Err(BlsError::InvalidAddress)
+    );
+}
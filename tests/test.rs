@@ -158,6 +158,24 @@ fn test_signature_with_dummy_key() {
     assert_eq!(signature_serialize_to_hex_str(&sig), sig_hex);
 }
 
+#[test]
+fn test_sign_checked() {
+    let mut sk = SecretKey::default();
+    sk.set_by_csprng();
+    let pk = sk.get_public_key();
+    let msg = "asdf".as_bytes();
+    assert_eq!(sk.sign_checked(&pk, msg).unwrap(), sk.sign(msg));
+
+    // a corrupted key no longer matches the public key computed beforehand
+    let mut buf = sk.serialize().unwrap();
+    buf[0] ^= 1;
+    let corrupted = SecretKey::from_serialized(&buf).unwrap();
+    assert_eq!(
+        corrupted.sign_checked(&pk, msg),
+        Err(BlsError::SignatureCheckFailed)
+    );
+}
+
 /// return true if `size`-byte split `msgs` are different each other
 /// * `msgs` - an array that `size`-byte messages are concatenated
 /// * `size` - length of one message
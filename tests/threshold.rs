@@ -0,0 +1,87 @@
+mod common;
+
+use multiversx_bls::*;
+
+use common::random_secret_key;
+
+const MSG: &[u8] = b"header hash";
+
+#[test]
+fn test_split_and_recover() {
+    let sk = random_secret_key();
+    let shares = split_secret_key(&sk, 3, &[1, 2, 3, 4, 5]).unwrap();
+    assert_eq!(shares.len(), 5);
+
+    let signatures: Vec<(u64, G1)> = shares
+        .iter()
+        .map(|share| (share.id(), share.sign(MSG).unwrap()))
+        .collect();
+    for share in &shares {
+        assert_ne!(share.public_key(), sk.get_public_key());
+    }
+
+    let expected = sk.sign(MSG);
+    for subset in [[0, 1, 2], [0, 2, 4], [4, 3, 1], [1, 2, 3]] {
+        let picked: Vec<(u64, G1)> = subset.iter().map(|i| signatures[*i]).collect();
+        assert_eq!(recover_signature(&picked).unwrap(), expected);
+    }
+    assert_ne!(recover_signature(&signatures[..2]).unwrap(), expected);
+    assert_eq!(recover_signature(&signatures).unwrap(), expected);
+}
+
+#[test]
+fn test_threshold_errors() {
+    let sk = random_secret_key();
+    assert_eq!(
+        split_secret_key(&sk, 0, &[1, 2]),
+        Err(BlsError::InvalidData)
+    );
+    assert_eq!(
+        split_secret_key(&sk, 3, &[1, 2]),
+        Err(BlsError::InvalidData)
+    );
+    assert_eq!(
+        split_secret_key(&sk, 2, &[1, 0, 2]),
+        Err(BlsError::InvalidData)
+    );
+    assert_eq!(
+        split_secret_key(&sk, 2, &[1, 2, 1]),
+        Err(BlsError::InvalidData)
+    );
+    assert_eq!(
+        split_secret_key(&SecretKey::default(), 1, &[1]),
+        Err(BlsError::InvalidData)
+    );
+
+    let signature = sk.sign(MSG);
+    assert_eq!(recover_signature(&[]), Err(BlsError::InvalidData));
+    assert_eq!(
+        recover_signature(&[(1, signature), (1, signature)]),
+        Err(BlsError::InvalidData)
+    );
+    assert_eq!(
+        recover_signature(&[(0, signature)]),
+        Err(BlsError::InvalidData)
+    );
+}
+
+#[test]
+fn test_signer() {
+    let sk = random_secret_key();
+    let shares = split_secret_key(&sk, 2, &[7, 11]).unwrap();
+    let signers: Vec<&dyn Signer> = vec![&sk, &shares[0], &shares[1]];
+
+    let msgs = [MSG, b"", b"proof"];
+    for signer in signers {
+        let signatures = signer.sign_batch(&msgs).unwrap();
+        for (signature, msg) in signatures.iter().zip(msgs) {
+            assert!(signature.verify(signer.public_key(), msg));
+            assert_eq!(*signature, signer.sign(msg).unwrap());
+        }
+    }
+
+    assert_eq!(
+        Signer::sign(&SecretKey::default(), MSG),
+        Err(BlsError::InvalidData)
+    );
+}
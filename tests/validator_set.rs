@@ -0,0 +1,137 @@
+mod common;
+
+use multiversx_bls::*;
+
+use common::random_secret_keys;
+
+fn validator_set(sks: &[SecretKey]) -> ValidatorSet {
+    ValidatorSet::new(sks.iter().map(|sk| sk.get_public_key()).collect()).unwrap()
+}
+
+#[test]
+fn test_bitmap_bit_order() {
+    let sks = random_secret_keys(11);
+    let set = validator_set(&sks);
+    assert_eq!(set.bitmap_size(), 2);
+
+    // validator 0 is the least significant bit of the first byte
+    assert_eq!(set.bitmap(&[0]).unwrap(), [0x01, 0x00]);
+    assert_eq!(set.bitmap(&[7, 8, 10]).unwrap(), [0x80, 0x05]);
+    assert_eq!(set.selected_indices(&[0x81, 0x04]).unwrap(), [0, 7, 10]);
+    assert_eq!(
+        set.selected_public_keys(&[0x02, 0x00]).unwrap(),
+        [sks[1].get_public_key()]
+    );
+    assert_eq!(set.bitmap(&[11]), Err(BlsError::InvalidBitmap));
+}
+
+#[test]
+fn test_verify_aggregated() {
+    let msg = b"header hash";
+    let sks = random_secret_keys(10);
+    let set = validator_set(&sks);
+
+    let indices = [0, 2, 3, 9];
+    let bitmap = set.bitmap(&indices).unwrap();
+    let mut signature = G1::default();
+    signature.aggregate(
+        &indices
+            .iter()
+            .map(|&i| sks[i].sign(msg))
+            .collect::<Vec<_>>(),
+    );
+
+    set.verify_aggregated(&bitmap, &signature, msg).unwrap();
+    let selected = set.selected_public_keys(&bitmap).unwrap();
+    assert!(signature.fast_aggregate_verify(&selected, msg));
+
+    let mut expected = G2::default();
+    for i in indices {
+        expected.add_assign(sks[i].get_public_key());
+    }
+    assert_eq!(set.aggregate_public_key(&bitmap).unwrap(), expected);
+
+    let other_bitmap = set.bitmap(&[0, 2, 3]).unwrap();
+    assert_eq!(
+        set.verify_aggregated(&other_bitmap, &signature, msg),
+        Err(BlsError::InvalidSignature)
+    );
+    assert_eq!(
+        set.verify_aggregated(&bitmap, &signature, b"other"),
+        Err(BlsError::InvalidSignature)
+    );
+}
+
+#[test]
+fn test_invalid_bitmaps() {
+    let sks = random_secret_keys(10);
+    let set = validator_set(&sks);
+    let signature = sks[0].sign(b"abc");
+
+    for bitmap in [
+        vec![],
+        vec![0x01],
+        vec![0x01, 0x00, 0x00],
+        // bit of an eleventh validator
+        vec![0x01, 0x04],
+        // no validator
+        vec![0x00, 0x00],
+    ] {
+        assert_eq!(
+            set.verify_aggregated(&bitmap, &signature, b"abc"),
+            Err(BlsError::InvalidBitmap)
+        );
+    }
+}
+
+#[test]
+fn test_validator_set_new() {
+    let sks = random_secret_keys(3);
+    let mut pks: Vec<G2> = sks.iter().map(|sk| sk.get_public_key()).collect();
+    let set = ValidatorSet::new(pks.clone()).unwrap();
+    assert_eq!(set.len(), 3);
+    assert_eq!(set.index_of(&pks[2]), Some(2));
+
+    assert_eq!(ValidatorSet::new(vec![]), Err(BlsError::InvalidData));
+    pks.push(pks[0]);
+    assert_eq!(
+        ValidatorSet::new(pks.clone()),
+        Err(BlsError::DuplicatePublicKey)
+    );
+    pks[3] = G2::default();
+    assert_eq!(ValidatorSet::new(pks), Err(BlsError::InvalidData));
+}
+
+#[test]
+fn test_aggregate_public_key_by_complement() {
+    let sks = random_secret_keys(13);
+    let set = validator_set(&sks);
+
+    let mut total = G2::default();
+    for sk in &sks {
+        total.add_assign(sk.get_public_key());
+    }
+    assert_eq!(set.total_public_key(), total);
+
+    for indices in [
+        vec![5],
+        vec![0, 1, 2, 3, 4, 5],
+        vec![0, 1, 2, 3, 4, 5, 6],
+        (0..12).collect(),
+        (0..13).collect(),
+    ] {
+        let bitmap = set.bitmap(&indices).unwrap();
+        let by_sum = set.aggregate_public_key_by_sum(&bitmap).unwrap();
+        assert_eq!(
+            set.aggregate_public_key_by_complement(&bitmap).unwrap(),
+            by_sum
+        );
+        assert_eq!(set.aggregate_public_key(&bitmap).unwrap(), by_sum);
+    }
+
+    let empty = set.bitmap(&[]).unwrap();
+    assert_eq!(
+        set.aggregate_public_key_by_complement(&empty),
+        Err(BlsError::InvalidBitmap)
+    );
+}
@@ -0,0 +1,131 @@
+mod common;
+
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+use multiversx_bls::vm_hooks;
+use multiversx_bls::*;
+
+use common::random_secret_key;
+
+struct Vector {
+    hook: String,
+    pubs: Vec<Vec<u8>>,
+    msg: Vec<u8>,
+    sig: Vec<u8>,
+    out: Result<(), BlsError>,
+}
+
+// `pub`, `msg` and `sig` are hex and may be empty, `out` is the expected result
+fn read_vectors() -> Vec<Vector> {
+    let file = BufReader::new(File::open("tests/vm_hooks.txt").unwrap());
+    let mut vectors = Vec::new();
+    let (mut hook, mut pubs, mut msg, mut sig) =
+        (String::new(), Vec::new(), Vec::new(), Vec::new());
+
+    for l in file.lines() {
+        let line = l.unwrap();
+        let elements: Vec<&str> = line.split_whitespace().collect();
+        let value = elements.get(1).copied().unwrap_or_default();
+        match elements[0] {
+            "hook" => hook = value.to_string(),
+            "pub" => pubs.push(hex::decode(value).unwrap()),
+            "msg" => msg = hex::decode(value).unwrap(),
+            "sig" => sig = hex::decode(value).unwrap(),
+            "out" => {
+                let out = match value {
+                    "ok" => Ok(()),
+                    "InvalidData" => Err(BlsError::InvalidData),
+                    "InvalidSignature" => Err(BlsError::InvalidSignature),
+                    _ => panic!("unknown result {value}"),
+                };
+                vectors.push(Vector {
+                    hook: std::mem::take(&mut hook),
+                    pubs: std::mem::take(&mut pubs),
+                    msg: std::mem::take(&mut msg),
+                    sig: std::mem::take(&mut sig),
+                    out,
+                });
+            }
+            _ => (),
+        }
+    }
+
+    vectors
+}
+
+#[test]
+fn test_hooks() {
+    for Vector {
+        hook,
+        pubs,
+        msg,
+        sig,
+        out,
+    } in read_vectors()
+    {
+        let key = pubs.first().cloned().unwrap_or_default();
+        let result = match hook.as_str() {
+            "verifyBLS" => vm_hooks::verify_bls(&key, &msg, &sig),
+            "verifyBLSSignatureShare" => vm_hooks::verify_bls_signature_share(&key, &msg, &sig),
+            "verifyBLSAggregatedSignature" => {
+                vm_hooks::verify_bls_aggregated_signature(&pubs, &msg, &sig)
+            }
+            _ => panic!("unknown hook {hook}"),
+        };
+        assert_eq!(result, out, "{hook} {}", hex::encode(&sig));
+
+        let code = if out.is_ok() {
+            vm_hooks::HOOK_SUCCESS
+        } else {
+            vm_hooks::HOOK_FAILURE
+        };
+        assert_eq!(vm_hooks::return_code(&result), code);
+    }
+}
+
+#[test]
+fn test_hooks_reject_invalid_points() {
+    let sk = random_secret_key();
+    let msg = b"message";
+    let key = sk.get_public_key().serialize().unwrap();
+    let sig = sk.sign(msg).serialize().unwrap();
+    vm_hooks::verify_bls(&key, msg, &sig).unwrap();
+
+    let zero_key = G2::default().serialize().unwrap();
+    let zero_sig = G1::default().serialize().unwrap();
+    assert_eq!(
+        vm_hooks::verify_bls(&zero_key, msg, &sig),
+        Err(BlsError::InvalidData)
+    );
+    assert_eq!(
+        vm_hooks::verify_bls(&key, msg, &zero_sig),
+        Err(BlsError::InvalidData)
+    );
+    assert_eq!(
+        vm_hooks::verify_bls_aggregated_signature(&[&key, &zero_key], msg, &sig),
+        Err(BlsError::InvalidData)
+    );
+    assert_eq!(
+        vm_hooks::verify_bls(&key[..95], msg, &sig),
+        Err(BlsError::InvalidData)
+    );
+}
+
+#[test]
+fn test_managed_vec_handles() {
+    let handles = [1, -20, 0x01020304];
+    let data = vm_hooks::encode_managed_vec_handles(&handles);
+    assert_eq!(hex::encode(&data), "00000001ffffffec01020304");
+    assert_eq!(
+        vm_hooks::decode_managed_vec_handles(&data).unwrap(),
+        handles
+    );
+    assert!(vm_hooks::decode_managed_vec_handles(&[])
+        .unwrap()
+        .is_empty());
+    assert_eq!(
+        vm_hooks::decode_managed_vec_handles(&data[1..]),
+        Err(BlsError::BadSize)
+    );
+}
@@ -0,0 +1,78 @@
+mod common;
+
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+use multiversx_bls::*;
+
+use common::random_secret_key;
+
+#[test]
+fn test_vrf_prove_verify() {
+    let sk = random_secret_key();
+    let pk = sk.get_public_key();
+    let input = b"epoch 1207 round 24164301";
+
+    let (output, proof) = sk.vrf_prove(input).unwrap();
+    assert!(proof.is_valid_order());
+    assert_eq!(pk.vrf_verify(input, &proof), Some(output));
+
+    // proofs are unique
+    assert_eq!(sk.vrf_prove(input).unwrap(), (output, proof));
+    let (other_output, other_proof) = sk.vrf_prove(b"epoch 1207 round 24164302").unwrap();
+    assert_ne!(other_output, output);
+    assert_eq!(pk.vrf_verify(input, &other_proof), None);
+
+    assert_eq!(
+        random_secret_key()
+            .get_public_key()
+            .vrf_verify(input, &proof),
+        None
+    );
+    assert_eq!(pk.vrf_verify(input, &G1::default()), None);
+    assert_eq!(G2::default().vrf_verify(input, &proof), None);
+    assert_eq!(
+        SecretKey::default().vrf_prove(input),
+        Err(BlsError::InvalidData)
+    );
+}
+
+#[test]
+fn test_vrf_domain_separation() {
+    let sk = random_secret_key();
+    let pk = sk.get_public_key();
+    let input = b"lottery draw";
+
+    // an ordinary signature of the input is not a VRF proof, and conversely
+    let (_, proof) = sk.vrf_prove(input).unwrap();
+    let signature = sk.sign(input);
+    assert_ne!(proof, signature);
+    assert_eq!(pk.vrf_verify(input, &signature), None);
+    assert!(!proof.verify(pk, input));
+}
+
+#[test]
+fn test_vrf_vectors() {
+    let file = BufReader::new(File::open("tests/vrf.txt").unwrap());
+    let (mut sk, mut input, mut proof) = (SecretKey::default(), Vec::new(), String::new());
+
+    for l in file.lines() {
+        let line = l.unwrap();
+        let v: Vec<&str> = line.split(' ').collect();
+        match v[0] {
+            "sec" => sk = SecretKey::from_serialized(&hex::decode(v[1]).unwrap()).unwrap(),
+            "input" => input = hex::decode(v[1]).unwrap(),
+            "proof" => proof = v[1].to_string(),
+            "out" => {
+                let (output, actual_proof) = sk.vrf_prove(&input).unwrap();
+                assert_eq!(hex::encode(actual_proof.serialize().unwrap()), proof);
+                assert_eq!(hex::encode(output), v[1]);
+                assert_eq!(
+                    sk.get_public_key().vrf_verify(&input, &actual_proof),
+                    Some(output)
+                );
+            }
+            _ => (),
+        }
+    }
+}